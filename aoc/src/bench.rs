@@ -0,0 +1,139 @@
+use crate::report::{format_ns, run, Format};
+use aoc_common::{Answer, Part, Solution};
+use serde::Serialize;
+use std::fmt;
+
+/// Summary of repeated measurements of the same thing.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<u64>) -> Self {
+        samples.sort_unstable();
+        Self {
+            min_ns: samples[0],
+            median_ns: percentile(&samples, 50),
+            p95_ns: percentile(&samples, 95),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>9}  median {:>9}  p95 {:>9}",
+            format_ns(self.min_ns),
+            format_ns(self.median_ns),
+            format_ns(self.p95_ns)
+        )
+    }
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[u64], p: usize) -> u64 {
+    let rank = (p * sorted.len()).div_ceil(100);
+    sorted[rank.max(1) - 1]
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct PhaseStats {
+    pub name: &'static str,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+/// Timings of solving the puzzle of one day many times.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Bench {
+    pub day: u8,
+    pub runs: usize,
+    pub total: Stats,
+    pub phases: Vec<PhaseStats>,
+}
+
+/// Solve the puzzle `runs` times, after one run to warm up.
+pub fn bench(
+    solution: &dyn Solution,
+    input: &str,
+    part: Option<Part>,
+    runs: usize,
+) -> Answer<Bench> {
+    run(solution, input, part)?;
+
+    let mut totals = vec![];
+    let mut phases: Vec<(&'static str, Vec<u64>)> = vec![];
+    for _ in 0..runs.max(1) {
+        let report = run(solution, input, part)?;
+        totals.push(report.elapsed_ns);
+        for phase in report.phases {
+            match phases.iter_mut().find(|(name, _)| *name == phase.name) {
+                Some((_, samples)) => samples.push(phase.elapsed_ns),
+                None => phases.push((phase.name, vec![phase.elapsed_ns])),
+            }
+        }
+    }
+
+    Ok(Bench {
+        day: solution.day(),
+        runs: totals.len(),
+        total: Stats::new(totals),
+        phases: phases
+            .into_iter()
+            .map(|(name, samples)| PhaseStats {
+                name,
+                stats: Stats::new(samples),
+            })
+            .collect(),
+    })
+}
+
+impl Bench {
+    pub fn print(&self, format: Format) {
+        match format {
+            Format::Text => {
+                println!("Day {}: {} runs", self.day, self.runs);
+                println!("  {:<6} {}", "total", self.total);
+                for phase in &self.phases {
+                    println!("  {:<6} {}", phase.name, phase.stats);
+                }
+            }
+            Format::Json => println!("{}", serde_json::to_string(self).unwrap()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_rank() {
+        assert_eq!(percentile(&[7], 50), 7);
+        assert_eq!(percentile(&[7], 95), 7);
+
+        let samples: Vec<u64> = (1..=100).collect();
+        assert_eq!(percentile(&samples, 50), 50);
+        assert_eq!(percentile(&samples, 95), 95);
+        assert_eq!(percentile(&samples, 0), 1);
+        assert_eq!(percentile(&samples, 100), 100);
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::new((1..=100).rev().collect());
+
+        assert_eq!(
+            stats,
+            Stats {
+                min_ns: 1,
+                median_ns: 50,
+                p95_ns: 95,
+            }
+        );
+    }
+}
@@ -0,0 +1,210 @@
+mod bench;
+mod report;
+mod verify;
+
+use aoc_common::{logger, Input, OrExit, Part, Solution};
+use bench::bench;
+use clap::{ArgAction, Parser, Subcommand};
+use report::{run, Format};
+use std::path::PathBuf;
+use verify::Expected;
+
+/// Runs the Advent of Code 2022 solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// How to print the results.
+    #[arg(long, global = true, value_enum, default_value_t)]
+    format: Format,
+    /// Print how long the parsing and solving took.
+    #[arg(long, global = true)]
+    time: bool,
+    /// Log the steps of the solutions, twice to trace everything.
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzle of a single day.
+    Run {
+        /// Day of the puzzle.
+        day: u8,
+        /// Only solve this part, instead of both.
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Puzzle input, `-` for stdin.
+        #[arg(default_value = "-")]
+        input: Input,
+        /// Known answers to check against, instead of the `.answers.toml` next
+        /// to the input.
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Solve the puzzle of a single day many times, reporting the timings.
+    Bench {
+        /// Day of the puzzle.
+        day: u8,
+        /// Only solve this part, instead of both.
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Puzzle input, `-` for stdin.
+        #[arg(default_value = "-")]
+        input: Input,
+        /// How often to solve the puzzle.
+        #[arg(long, default_value_t = 100)]
+        runs: usize,
+    },
+    /// Solve every day with its `input.txt` and check the answers against its
+    /// `input.answers.toml`.
+    Check {
+        /// Directory holding the `day_XX` directories.
+        #[arg(default_value = ".")]
+        root: PathBuf,
+    },
+}
+
+fn parse_part(s: &str) -> Result<Part, String> {
+    match s {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("Invalid part: {s}")),
+    }
+}
+
+fn solution(day: u8) -> Option<Box<dyn Solution>> {
+    match day {
+        1 => Some(Box::new(day_01::Day01)),
+        2 => Some(Box::new(day_02::Day02)),
+        3 => Some(Box::new(day_03::Day03)),
+        4 => Some(Box::new(day_04::Day04)),
+        5 => Some(Box::new(day_05::Day05)),
+        6 => Some(Box::new(day_06::Day06)),
+        7 => Some(Box::new(day_07::Day07)),
+        _ => None,
+    }
+}
+
+/// Solve every day, exiting with an error if any answer is wrong.
+fn check(root: PathBuf, format: Format, time: bool) {
+    let mut failed = false;
+    for solution in (1..=25).filter_map(solution) {
+        let day = solution.day();
+        let dir = root.join(format!("day_{day:02}"));
+
+        let input = Input::Path(dir.join("input.txt"));
+        let report = input
+            .read_to_string()
+            .map_err(Into::into)
+            .and_then(|contents| run(solution.as_ref(), &contents, None));
+        let expected = match Expected::sidecar(&input) {
+            Some(path) if path.exists() => Expected::load(&path),
+            _ => Ok(Expected::default()),
+        };
+
+        match (report, expected) {
+            (Ok(mut report), Ok(expected)) => {
+                report.verify(&expected);
+                failed |= report.failed();
+                report.print(format, time);
+            }
+            (Err(err), _) | (_, Err(err)) => {
+                eprintln!("Day {day}: {err}");
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    logger::init(cli.verbose);
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            answers,
+        } => {
+            let solution = solution(day).unwrap_or_else(|| {
+                eprintln!("No solution for day {day}.");
+                std::process::exit(1);
+            });
+
+            let contents = input.read_to_string().or_exit();
+
+            let mut report = run(solution.as_ref(), &contents, part).unwrap_or_else(|err| {
+                eprintln!("Day {day}: {err}");
+                std::process::exit(1);
+            });
+            let answers =
+                answers.or_else(|| Expected::sidecar(&input).filter(|path| path.exists()));
+            if let Some(path) = answers {
+                report.verify(&Expected::load(&path).or_exit());
+            }
+            report.print(cli.format, cli.time);
+            if report.failed() {
+                std::process::exit(1);
+            }
+        }
+        Command::Bench {
+            day,
+            part,
+            input,
+            runs,
+        } => {
+            let solution = solution(day).unwrap_or_else(|| {
+                eprintln!("No solution for day {day}.");
+                std::process::exit(1);
+            });
+
+            let contents = input.read_to_string().or_exit();
+
+            let bench = bench(solution.as_ref(), &contents, part, runs).unwrap_or_else(|err| {
+                eprintln!("Day {day}: {err}");
+                std::process::exit(1);
+            });
+            bench.print(cli.format);
+        }
+        Command::Check { root } => check(root, cli.format, cli.time),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use verify::Verdict;
+
+    #[test]
+    fn examples_pass() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+        for solution in (1..=25).filter_map(solution) {
+            let input = Input::Path(root.join(format!("day_{:02}/example.txt", solution.day())));
+            let contents = input.read_to_string().unwrap();
+            let mut report = run(solution.as_ref(), &contents, None).unwrap();
+
+            let path = Expected::sidecar(&input).unwrap();
+            assert!(path.ends_with("example.answers.toml"));
+            report.verify(&Expected::load(&path).unwrap());
+            assert_eq!(
+                report.part1_verdict,
+                Some(Verdict::Pass),
+                "day {}",
+                report.day
+            );
+            assert_eq!(
+                report.part2_verdict,
+                Some(Verdict::Pass),
+                "day {}",
+                report.day
+            );
+        }
+    }
+}
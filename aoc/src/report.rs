@@ -0,0 +1,127 @@
+use crate::verify::{Expected, Verdict};
+use aoc_common::{timing, Answer, Answers, Part, Solution};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::time::Instant;
+
+/// How the results are printed.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// One line per answer, for humans.
+    #[default]
+    Text,
+    /// One JSON object per day, for scripts.
+    Json,
+}
+
+/// How long one phase of a solution took.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct PhaseTime {
+    pub name: &'static str,
+    pub elapsed_ns: u64,
+}
+
+/// Results of solving the puzzle of one day.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Report {
+    pub day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+    pub elapsed_ns: u64,
+    pub phases: Vec<PhaseTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1_verdict: Option<Verdict>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2_verdict: Option<Verdict>,
+}
+
+/// Solve the given part, or both parts, timing how long it takes.
+pub fn run(solution: &dyn Solution, input: &str, part: Option<Part>) -> Answer<Report> {
+    timing::take();
+    let start = Instant::now();
+    let (part1, part2) = match part {
+        Some(Part::One) => (Some(solution.part1(input)?), None),
+        Some(Part::Two) => (None, Some(solution.part2(input)?)),
+        None => {
+            let Answers { part1, part2 } = solution.answers(input)?;
+            (Some(part1), Some(part2))
+        }
+    };
+    let elapsed = start.elapsed();
+
+    Ok(Report {
+        day: solution.day(),
+        part1,
+        part2,
+        elapsed_ns: elapsed.as_nanos() as u64,
+        phases: timing::take()
+            .into_iter()
+            .map(|phase| PhaseTime {
+                name: phase.name,
+                elapsed_ns: phase.elapsed.as_nanos() as u64,
+            })
+            .collect(),
+        part1_verdict: None,
+        part2_verdict: None,
+    })
+}
+
+/// Human-readable duration, with the unit chosen by its magnitude.
+pub fn format_ns(ns: u64) -> String {
+    match ns {
+        0..=999 => format!("{ns}ns"),
+        1_000..=999_999 => format!("{:.1}µs", ns as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", ns as f64 / 1e6),
+        _ => format!("{:.2}s", ns as f64 / 1e9),
+    }
+}
+
+impl Report {
+    /// Check the answers against the known-correct ones.
+    pub fn verify(&mut self, expected: &Expected) {
+        self.part1_verdict = self.part1.as_ref().map(|a| expected.check(Part::One, a));
+        self.part2_verdict = self.part2.as_ref().map(|a| expected.check(Part::Two, a));
+    }
+
+    /// Whether any answer is known to be wrong.
+    pub fn failed(&self) -> bool {
+        [self.part1_verdict, self.part2_verdict].contains(&Some(Verdict::Fail))
+    }
+
+    /// Print the report. In text format, the timings are only printed if asked for.
+    pub fn print(&self, format: Format, time: bool) {
+        match format {
+            Format::Text => {
+                let parts = [
+                    (1, &self.part1, self.part1_verdict),
+                    (2, &self.part2, self.part2_verdict),
+                ];
+                for (part, answer, verdict) in parts {
+                    match (answer, verdict) {
+                        (Some(answer), Some(verdict)) => {
+                            println!("Day {} part {part}: {answer} [{verdict}]", self.day)
+                        }
+                        (Some(answer), None) => println!("Day {} part {part}: {answer}", self.day),
+                        (None, _) => {}
+                    }
+                }
+                if time {
+                    let phases = self
+                        .phases
+                        .iter()
+                        .map(|phase| format!("{} {}", phase.name, format_ns(phase.elapsed_ns)))
+                        .collect::<Vec<String>>();
+                    println!(
+                        "Day {} took {} ({})",
+                        self.day,
+                        format_ns(self.elapsed_ns),
+                        phases.join(", ")
+                    );
+                }
+            }
+            Format::Json => println!("{}", serde_json::to_string(self).unwrap()),
+        }
+    }
+}
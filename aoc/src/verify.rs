@@ -0,0 +1,132 @@
+use aoc_common::{Answer, Input, Part};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Extension replacing the one of an input for the file holding the known
+/// answers for it, like `input.answers.toml` for `input.txt`.
+pub const SIDECAR_EXTENSION: &str = "answers.toml";
+
+/// Whether an answer matches the known-correct one.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "PASS"),
+            Self::Fail => write!(f, "FAIL"),
+            Self::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+/// Answers may be written as strings or, if they are numbers, as integers.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Value {
+    Text(String),
+    Number(i64),
+}
+
+impl From<Value> for String {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Text(text) => text,
+            Value::Number(number) => number.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct File {
+    part1: Option<Value>,
+    part2: Option<Value>,
+}
+
+/// The known-correct answers for an input, as far as they are known.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl From<File> for Expected {
+    fn from(file: File) -> Self {
+        Self {
+            part1: file.part1.map(String::from),
+            part2: file.part2.map(String::from),
+        }
+    }
+}
+
+impl Expected {
+    pub fn load(path: &Path) -> Answer<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Problem reading {}: {err}", path.display()))?;
+        let file: File = toml::from_str(&contents)
+            .map_err(|err| format!("Problem parsing {}: {err}", path.display()))?;
+
+        Ok(file.into())
+    }
+
+    /// Path of the answers file next to the input, if the input is a file.
+    pub fn sidecar(input: &Input) -> Option<PathBuf> {
+        match input {
+            Input::Path(path) => Some(path.with_extension(SIDECAR_EXTENSION)),
+            _ => None,
+        }
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Verdict {
+        let expected = match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        };
+        match expected {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(contents: &str) -> Expected {
+        toml::from_str::<File>(contents).unwrap().into()
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(
+            parse("part1 = 24000\npart2 = \"MCD\"\n"),
+            Expected {
+                part1: Some("24000".to_string()),
+                part2: Some("MCD".to_string()),
+            }
+        );
+        assert_eq!(parse("part1 = \"007\"\n").part1.as_deref(), Some("007"));
+        assert_eq!(parse(""), Expected::default());
+        assert!(toml::from_str::<File>("part3 = 1\n").is_err());
+        assert!(toml::from_str::<File>("part1 = 1.5\n").is_err());
+    }
+
+    #[test]
+    fn check() {
+        let expected = parse("part1 = 157\n");
+
+        assert_eq!(expected.check(Part::One, "157"), Verdict::Pass);
+        assert_eq!(expected.check(Part::One, "158"), Verdict::Fail);
+        assert_eq!(expected.check(Part::Two, "70"), Verdict::Unknown);
+    }
+}
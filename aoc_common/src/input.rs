@@ -0,0 +1,89 @@
+use std::convert::Infallible;
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Where the puzzle input is read from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Input {
+    Path(PathBuf),
+    Stdin,
+    Embedded(&'static str),
+}
+
+impl Input {
+    /// Input named by the first command line argument, `-` meaning stdin.
+    pub fn from_args() -> Result<Self, InputError> {
+        match env::args().nth(1) {
+            Some(arg) => Ok(arg.parse().unwrap()),
+            None => Err(InputError::Missing),
+        }
+    }
+
+    pub fn reader(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Self::Path(path) => match File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(err) => Err(InputError::Io(self.clone(), err)),
+            },
+            Self::Stdin => Ok(Box::new(BufReader::new(io::stdin()))),
+            Self::Embedded(contents) => Ok(Box::new(contents.as_bytes())),
+        }
+    }
+
+    pub fn read_to_string(&self) -> Result<String, InputError> {
+        let mut contents = String::new();
+        self.reader()?
+            .read_to_string(&mut contents)
+            .map_err(|err| InputError::Io(self.clone(), err))?;
+        Ok(contents)
+    }
+}
+
+impl FromStr for Input {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(Self::Stdin),
+            path => Ok(Self::Path(PathBuf::from(path))),
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Path(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+            Self::Embedded(_) => write!(f, "embedded input"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing,
+    Io(Input, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => write!(f, "File path missing."),
+            Self::Io(input, err) => write!(f, "Problem reading {input}: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Missing => None,
+            Self::Io(_, err) => Some(err),
+        }
+    }
+}
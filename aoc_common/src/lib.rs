@@ -0,0 +1,76 @@
+pub mod input;
+pub mod logger;
+pub mod paragraphs;
+pub mod pos;
+pub mod timing;
+
+pub use input::{Input, InputError};
+pub use paragraphs::{ParagraphError, Paragraphs};
+pub use pos::{columns, Pos};
+pub use timing::phase;
+
+use std::error::Error;
+use std::fmt;
+
+/// One of the two parts every puzzle consists of.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", *self as u8)
+    }
+}
+
+/// The answer to one part of a puzzle, or why it could not be found.
+pub type Answer<T = String> = Result<T, Box<dyn Error>>;
+
+/// The answers to both parts of a puzzle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+}
+
+/// A solver for a single day of the Advent of Code.
+pub trait Solution {
+    /// The day of the advent calendar this solves.
+    fn day(&self) -> u8;
+
+    fn part1(&self, input: &str) -> Answer;
+
+    fn part2(&self, input: &str) -> Answer;
+
+    fn solve(&self, input: &str, part: Part) -> Answer {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+
+    /// Solve both parts in one run. Solutions whose parts share the parsed
+    /// input override this to only parse it once.
+    fn answers(&self, input: &str) -> Answer<Answers> {
+        Ok(Answers {
+            part1: self.part1(input)?,
+            part2: self.part2(input)?,
+        })
+    }
+}
+
+/// Report an error on stderr and exit, the way the binaries handle bad input.
+pub trait OrExit<T> {
+    fn or_exit(self) -> T;
+}
+
+impl<T, E: fmt::Display> OrExit<T> for Result<T, E> {
+    fn or_exit(self) -> T {
+        self.unwrap_or_else(|err| {
+            eprintln!("{err}");
+            std::process::exit(1);
+        })
+    }
+}
@@ -0,0 +1,151 @@
+use crate::Pos;
+use std::fmt;
+use std::io::{self, BufRead, Lines};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Groups of lines separated by blank lines, as many puzzle inputs are made of.
+///
+/// Every blank line ends a paragraph, so a blank line at the start of the
+/// input or directly after another blank line ends an empty one. The input
+/// ending without a blank line ends the last paragraph, unless it is empty.
+pub struct Paragraphs<R> {
+    lines: Lines<R>,
+    /// Number of lines read so far.
+    read: usize,
+    line: usize,
+}
+
+impl<R: BufRead> Paragraphs<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            read: 0,
+            line: 0,
+        }
+    }
+
+    /// Line number at which the paragraph returned last starts, or for an
+    /// empty one, of the blank line ending it.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The next paragraph, with every line parsed.
+    pub fn next_parsed<T: FromStr>(&mut self) -> Option<Result<Vec<T>, ParagraphError<T::Err>>> {
+        let lines = match self.next()? {
+            Ok(lines) => lines,
+            Err(err) => return Some(Err(ParagraphError::Io(err))),
+        };
+        let start = self.line;
+
+        let parsed = lines.iter().enumerate().map(|(i, line)| {
+            line.parse().map_err(|err| ParagraphError::Parse {
+                pos: Pos::new(start + i, 1),
+                err,
+            })
+        });
+        Some(parsed.collect())
+    }
+
+    /// Every remaining paragraph, with every line parsed.
+    pub fn parsed<T: FromStr>(self) -> Parsed<R, T> {
+        Parsed {
+            paragraphs: self,
+            item: PhantomData,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Paragraphs<R> {
+    type Item = io::Result<Vec<String>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut lines = vec![];
+        self.line = self.read + 1;
+        for line in self.lines.by_ref() {
+            self.read += 1;
+            match line {
+                Ok(line) if line.is_empty() => return Some(Ok(lines)),
+                Ok(line) => lines.push(line),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+
+        match lines.is_empty() {
+            true => None,
+            false => Some(Ok(lines)),
+        }
+    }
+}
+
+/// Paragraphs with every line parsed, see [`Paragraphs::parsed`].
+pub struct Parsed<R, T> {
+    paragraphs: Paragraphs<R>,
+    item: PhantomData<T>,
+}
+
+impl<R: BufRead, T: FromStr> Iterator for Parsed<R, T> {
+    type Item = Result<Vec<T>, ParagraphError<T::Err>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.paragraphs.next_parsed()
+    }
+}
+
+#[derive(Debug)]
+pub enum ParagraphError<E> {
+    Io(io::Error),
+    /// A line could not be parsed, `pos` being where it starts.
+    Parse {
+        pos: Pos,
+        err: E,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for ParagraphError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Problem reading input: {err}"),
+            Self::Parse { pos, err } => write!(f, "{pos}: {err}"),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for ParagraphError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Parse { err, .. } => Some(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paragraphs() {
+        let mut paragraphs = Paragraphs::new("\na\nb\n\n\nc\n".as_bytes());
+        let mut next = || Some((paragraphs.next()?.unwrap(), paragraphs.line()));
+
+        assert_eq!(next(), Some((vec![], 1)));
+        assert_eq!(next(), Some((vec!["a".to_string(), "b".to_string()], 2)));
+        assert_eq!(next(), Some((vec![], 5)));
+        assert_eq!(next(), Some((vec!["c".to_string()], 6)));
+        assert_eq!(next(), None);
+    }
+
+    #[test]
+    fn parsed() {
+        let mut parsed = Paragraphs::new("1\n2\n\n3\nx\n".as_bytes()).parsed::<i32>();
+
+        assert_eq!(parsed.next().unwrap().unwrap(), vec![1, 2]);
+        assert!(matches!(
+            parsed.next(),
+            Some(Err(ParagraphError::Parse { pos, .. })) if pos == Pos::new(5, 1)
+        ));
+        assert!(parsed.next().is_none());
+    }
+}
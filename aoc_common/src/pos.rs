@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// Position of a token in the puzzle input. Lines and columns count from 1.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Pos {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Pos {
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    /// Translate a position found by a parser that only saw the part of the
+    /// input starting at `origin` into a position in the whole input.
+    pub fn offset(self, origin: Pos) -> Self {
+        if self.line == 1 {
+            Self::new(origin.line, origin.column + self.column - 1)
+        } else {
+            Self::new(origin.line + self.line - 1, self.column)
+        }
+    }
+}
+
+impl Default for Pos {
+    fn default() -> Self {
+        Self::new(1, 1)
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Split `s` at `sep`, pairing every token with the column it starts at.
+pub fn columns<'a>(s: &'a str, sep: &'a str) -> impl Iterator<Item = (usize, &'a str)> {
+    let mut column = 1;
+    s.split(sep).map(move |token| {
+        let start = column;
+        column += token.len() + sep.len();
+        (start, token)
+    })
+}
@@ -0,0 +1,35 @@
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// How long a phase of a solution, like parsing the input, took.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Phase {
+    pub name: &'static str,
+    pub elapsed: Duration,
+}
+
+thread_local! {
+    static PHASES: RefCell<Vec<Phase>> = const { RefCell::new(vec![]) };
+}
+
+/// Run `f` as the named phase, recording how long it took. Phases of the same
+/// name add up, so parsing the input for both parts counts as one phase.
+pub fn phase<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+
+    PHASES.with(|phases| {
+        let mut phases = phases.borrow_mut();
+        match phases.iter_mut().find(|phase| phase.name == name) {
+            Some(phase) => phase.elapsed += elapsed,
+            None => phases.push(Phase { name, elapsed }),
+        }
+    });
+    result
+}
+
+/// Take the phases recorded on this thread so far, in the order they started.
+pub fn take() -> Vec<Phase> {
+    PHASES.with(|phases| phases.take())
+}
@@ -0,0 +1,60 @@
+use aoc_common::Pos;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    InvalidCalories { pos: Pos, token: String },
+    Overflow { pos: Pos, idx: i32 },
+    TotalOverflow,
+}
+
+impl Error {
+    /// Move the error from the position in a part of the list to the position
+    /// in the whole list, with `elves` elves numbered before that part.
+    pub fn offset(self, origin: Pos, elves: i32) -> Self {
+        match self {
+            Self::Io(err) => Self::Io(err),
+            Self::InvalidCalories { pos, token } => Self::InvalidCalories {
+                pos: pos.offset(origin),
+                token,
+            },
+            Self::Overflow { pos, idx } => Self::Overflow {
+                pos: pos.offset(origin),
+                idx: idx + elves,
+            },
+            Self::TotalOverflow => Self::TotalOverflow,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Problem reading input: {err}"),
+            Self::InvalidCalories { pos, token } => {
+                write!(f, "{pos}: invalid amount of calories '{token}'")
+            }
+            Self::Overflow { pos, idx } => {
+                write!(f, "{pos}: too many calories carried by elf {idx}")
+            }
+            Self::TotalOverflow => write!(f, "too many calories carried by all elves"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
@@ -0,0 +1,337 @@
+mod error;
+pub mod parallel;
+pub mod ranking;
+pub mod stats;
+pub mod top;
+
+pub use error::Error;
+
+use aoc_common::{phase, Answer, Answers, Paragraphs, Pos, Solution};
+use std::fmt;
+use std::io::BufRead;
+
+/// How the lines of the calorie list are grouped into elves.
+///
+/// Every elf's items are separated from the next elf's by blank lines. An elf
+/// whose items add up to zero calories is still an elf, and so is kept.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Grouping {
+    /// Whether a run of blank lines separates just two elves, and blank lines
+    /// at the start or end of the list are ignored. Otherwise, every blank
+    /// line not directly following an item ends an elf carrying nothing.
+    pub collapse_blank_runs: bool,
+    /// Whether the elves carrying nothing are yielded. Either way, they are
+    /// counted when numbering the elves.
+    pub keep_empty: bool,
+}
+
+/// Collapses blank runs, as the puzzle input has none.
+impl Default for Grouping {
+    fn default() -> Self {
+        Self {
+            collapse_blank_runs: true,
+            keep_empty: false,
+        }
+    }
+}
+
+pub struct Elves<B> {
+    paragraphs: Paragraphs<B>,
+    grouping: Grouping,
+    last_idx: i32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Elf {
+    pub idx: i32,
+    /// Sum of the calories of all items.
+    pub calories: u64,
+    /// Calories of every food item, in the order of the list.
+    pub items: Vec<u64>,
+}
+
+impl Elf {
+    pub fn largest_item(&self) -> Option<u64> {
+        self.items.iter().max().copied()
+    }
+
+    /// Whether the elf carries any item of more than `calories`.
+    pub fn carries_over(&self, calories: u64) -> bool {
+        self.items.iter().any(|&item| item > calories)
+    }
+}
+
+/// Writes the items the way the calorie list does, one per line.
+impl fmt::Display for Elf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for item in &self.items {
+            writeln!(f, "{item}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<B: BufRead> Elves<B> {
+    fn next_elf(&mut self) -> Result<Option<Elf>, Error> {
+        for lines in self.paragraphs.by_ref() {
+            let lines = lines?;
+            if lines.is_empty() {
+                if self.grouping.collapse_blank_runs {
+                    continue;
+                }
+                self.last_idx += 1;
+                if !self.grouping.keep_empty {
+                    continue;
+                }
+            } else {
+                self.last_idx += 1;
+            }
+
+            let mut calories: u64 = 0;
+            let mut items = vec![];
+            for (i, amount) in lines.into_iter().enumerate() {
+                let pos = Pos::new(self.paragraphs.line() + i, 1);
+                let amount = amount
+                    .parse::<u64>()
+                    .map_err(|_| Error::InvalidCalories { pos, token: amount })?;
+                calories = calories.checked_add(amount).ok_or(Error::Overflow {
+                    pos,
+                    idx: self.last_idx,
+                })?;
+                items.push(amount);
+            }
+
+            return Ok(Some(Elf {
+                idx: self.last_idx,
+                calories,
+                items,
+            }));
+        }
+        Ok(None)
+    }
+}
+
+impl<B: BufRead> Iterator for Elves<B> {
+    type Item = Result<Elf, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_elf().transpose()
+    }
+}
+
+/// Group the calorie list into elves, with the default grouping.
+pub fn elves<B: BufRead>(reader: B) -> Elves<B> {
+    grouped_elves(reader, Grouping::default())
+}
+
+pub fn grouped_elves<B: BufRead>(reader: B, grouping: Grouping) -> Elves<B> {
+    Elves {
+        paragraphs: Paragraphs::new(reader),
+        grouping,
+        last_idx: 0,
+    }
+}
+
+/// The elf carrying the largest single item, and that item. Of several, the
+/// first one.
+pub fn largest_item(elves: &[Elf]) -> Option<(&Elf, u64)> {
+    elves
+        .iter()
+        .filter_map(|elf| Some((elf, elf.largest_item()?)))
+        .rev()
+        .max_by_key(|&(_, item)| item)
+}
+
+/// The elf carrying the most items. Of several, the first one.
+pub fn most_items(elves: &[Elf]) -> Option<&Elf> {
+    elves.iter().rev().max_by_key(|elf| elf.items.len())
+}
+
+/// The elves carrying any item of more than `calories`.
+pub fn carrying_over(elves: &[Elf], calories: u64) -> impl Iterator<Item = &Elf> {
+    elves.iter().filter(move |elf| elf.carries_over(calories))
+}
+
+/// Write the elves back as a calorie list, separated by blank lines.
+pub fn write_list<'a>(
+    f: &mut impl std::io::Write,
+    elves: impl IntoIterator<Item = &'a Elf>,
+) -> std::io::Result<()> {
+    for (i, elf) in elves.into_iter().enumerate() {
+        if i > 0 {
+            writeln!(f)?;
+        }
+        write!(f, "{elf}")?;
+    }
+    Ok(())
+}
+
+/// The `k` elves carrying the most calories, the most first. Of elves carrying
+/// the same, the ones further up the list come first.
+///
+/// The list is parsed while it is streamed into the heap, so parsing is timed
+/// as part of the "solve" phase.
+pub fn top_elves<B: BufRead>(elves: Elves<B>, k: usize) -> Result<Vec<Elf>, Error> {
+    phase("solve", || top::top_k_by_key(elves, k, |elf| elf.calories))
+}
+
+/// Sum of the calories carried by all the elves.
+pub fn total_calories<'a>(elves: impl IntoIterator<Item = &'a Elf>) -> Result<u64, Error> {
+    elves
+        .into_iter()
+        .try_fold(0u64, |total, elf| total.checked_add(elf.calories))
+        .ok_or(Error::TotalOverflow)
+}
+
+/// Sum of the calories carried by the `n` elves carrying the most.
+pub fn top_calories<B: BufRead>(elves: Elves<B>, n: usize) -> Result<u64, Error> {
+    total_calories(&top_elves(elves, n)?)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        Ok(top_calories(elves(input.as_bytes()), 1)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        Ok(top_calories(elves(input.as_bytes()), 3)?.to_string())
+    }
+
+    fn answers(&self, input: &str) -> Answer<Answers> {
+        let top = top_elves(elves(input.as_bytes()), 3)?;
+        Ok(Answers {
+            part1: total_calories(top.iter().take(1))?.to_string(),
+            part2: total_calories(&top)?.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(Day01.part1(EXAMPLE).unwrap(), "24000");
+        assert_eq!(Day01.part2(EXAMPLE).unwrap(), "45000");
+    }
+
+    #[test]
+    fn elves_are_grouped_by_blank_lines() {
+        let calories: Vec<(i32, u64)> = elves(EXAMPLE.as_bytes())
+            .map(|elf| elf.map(|elf| (elf.idx, elf.calories)))
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(
+            calories,
+            vec![(1, 6000), (2, 4000), (3, 11000), (4, 24000), (5, 10000)]
+        );
+    }
+
+    #[test]
+    fn item_queries() {
+        let elves: Vec<Elf> = elves(EXAMPLE.as_bytes()).collect::<Result<_, _>>().unwrap();
+
+        let (elf, item) = largest_item(&elves).unwrap();
+        assert_eq!((elf.idx, item), (5, 10000));
+        assert_eq!(most_items(&elves).unwrap().idx, 1);
+
+        let over: Vec<i32> = carrying_over(&elves, 6000).map(|elf| elf.idx).collect();
+        assert_eq!(over, vec![4, 5]);
+    }
+
+    #[test]
+    fn round_trip() {
+        let elves: Vec<Elf> = elves(EXAMPLE.as_bytes()).collect::<Result<_, _>>().unwrap();
+
+        let mut list = vec![];
+        write_list(&mut list, &elves).unwrap();
+        assert_eq!(String::from_utf8(list).unwrap(), EXAMPLE);
+    }
+
+    #[test]
+    fn top_elves_of_example() {
+        let top: Vec<(i32, u64)> = top_elves(elves(EXAMPLE.as_bytes()), 3)
+            .unwrap()
+            .iter()
+            .map(|elf| (elf.idx, elf.calories))
+            .collect();
+
+        assert_eq!(top, vec![(4, 24000), (3, 11000), (5, 10000)]);
+        assert_eq!(top_elves(elves(EXAMPLE.as_bytes()), 0).unwrap().len(), 0);
+        assert_eq!(top_elves(elves(EXAMPLE.as_bytes()), 10).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn top_keeps_first_of_ties() {
+        let items = [(1, 3), (2, 1), (3, 3), (4, 2), (5, 3)].map(Ok::<_, ()>);
+        let top = top::top_k_by_key(items, 2, |&(_, calories)| calories);
+
+        assert_eq!(top, Ok(vec![(1, 3), (3, 3)]));
+    }
+
+    fn grouped(input: &str, collapse_blank_runs: bool, keep_empty: bool) -> Vec<(i32, u64)> {
+        let grouping = Grouping {
+            collapse_blank_runs,
+            keep_empty,
+        };
+        grouped_elves(input.as_bytes(), grouping)
+            .map(|elf| elf.map(|elf| (elf.idx, elf.calories)))
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn grouping() {
+        let input = "\n1\n\n\n\n0\n\n2\n\n";
+
+        assert_eq!(grouped(input, true, false), vec![(1, 1), (2, 0), (3, 2)]);
+        assert_eq!(grouped(input, true, true), vec![(1, 1), (2, 0), (3, 2)]);
+        assert_eq!(grouped(input, false, false), vec![(2, 1), (5, 0), (6, 2)]);
+        assert_eq!(
+            grouped(input, false, true),
+            vec![(1, 0), (2, 1), (3, 0), (4, 0), (5, 0), (6, 2)]
+        );
+        assert_eq!(grouped("1\n\n0", true, false), vec![(1, 1), (2, 0)]);
+        assert_eq!(grouped("", false, true), vec![]);
+    }
+
+    #[test]
+    fn overflow() {
+        let input = format!("1\n\n{}\n1\n", u64::MAX);
+        let err = top_calories(elves(input.as_bytes()), 1).unwrap_err();
+
+        assert!(matches!(err, Error::Overflow { pos, idx: 2 } if pos == Pos::new(4, 1)));
+
+        let input = format!("1\n\n{}\n", u64::MAX);
+        let err = top_calories(elves(input.as_bytes()), 2).unwrap_err();
+
+        assert!(matches!(err, Error::TotalOverflow));
+    }
+
+    #[test]
+    fn invalid_calories() {
+        let err = top_calories(elves("100\n\n1x0\n".as_bytes()), 1).unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::InvalidCalories { pos, token } if pos == Pos::new(3, 1) && token == "1x0"
+        ));
+
+        let err = top_calories(elves("100\n-3\n".as_bytes()), 1).unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::InvalidCalories { pos, token } if pos == Pos::new(2, 1) && token == "-3"
+        ));
+    }
+}
@@ -1,77 +1,154 @@
-use std::env;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Error, Lines};
+use aoc_common::{Input, OrExit};
+use clap::{Parser, ValueEnum};
+use day_01::parallel::top_elves_mapped;
+use day_01::ranking::{ranking, Ties};
+use day_01::stats::Stats;
+use day_01::{
+    carrying_over, grouped_elves, largest_item, most_items, top_elves, total_calories, write_list,
+    Elf, Grouping,
+};
+use std::io;
 
-struct Elves {
-    buffer: Lines<BufReader<File>>,
-    last_idx: i32,
+/// How the ranking is exported.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+enum Export {
+    Csv,
+    Json,
 }
 
-#[derive(Debug)]
-struct Elf {
-    idx: i32,
-    calories: i32,
+/// Finds the elves carrying the most calories.
+#[derive(Parser)]
+struct Cli {
+    /// Calorie list, `-` for stdin.
+    input: Input,
+    /// Sum up the calories of this many elves, instead of the top 1 and 3.
+    #[arg(long, short = 'k')]
+    top: Option<usize>,
+    /// Read every blank line not directly following an item as an elf
+    /// carrying nothing, instead of collapsing runs of blank lines.
+    #[arg(long)]
+    keep_blank_runs: bool,
+    /// Also report the elves carrying nothing. Only with `--keep-blank-runs`,
+    /// as otherwise there are none.
+    #[arg(long, requires = "keep_blank_runs")]
+    keep_empty: bool,
+    /// Report statistics about all elves instead.
+    #[arg(long)]
+    stats: bool,
+    /// Percentiles to report with `--stats`.
+    #[arg(
+        long = "percentile",
+        value_parser = clap::value_parser!(u8).range(0..=100),
+        default_values_t = [25, 50, 75, 90, 99]
+    )]
+    percentiles: Vec<u8>,
+    /// Number of bins of the histogram reported with `--stats`.
+    #[arg(long, default_value_t = 10)]
+    bins: usize,
+    /// Find the largest single item instead.
+    #[arg(long)]
+    largest_item: bool,
+    /// Find the elf carrying the most items instead.
+    #[arg(long)]
+    most_items: bool,
+    /// Write out the list of only the elves carrying any item of more than
+    /// this many calories instead.
+    #[arg(long, value_name = "CALORIES")]
+    over: Option<u64>,
+    /// Export the ranking of all elves instead, or with `--top` of the top
+    /// ones.
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    ranking: Option<Export>,
+    /// How to rank elves carrying the same: `competition` (1224), `dense`
+    /// (1223) or `ordinal` (1234).
+    #[arg(long, default_value = "competition")]
+    ties: Ties,
+    /// Memory-map the list and split it up among this many threads to find
+    /// the top elves. Only for lists read from a file.
+    #[arg(long)]
+    threads: Option<usize>,
 }
 
-impl Iterator for Elves {
-    type Item = Elf;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        let mut calories = 0;
-        while let Some(amount) = self.buffer.next() {
-            let amount = amount.unwrap();
+fn main() {
+    let cli = Cli::parse();
+    let grouping = Grouping {
+        collapse_blank_runs: !cli.keep_blank_runs,
+        keep_empty: cli.keep_empty,
+    };
 
-            if amount.is_empty() {
-                self.last_idx += 1;
-                return Some(Elf {
-                    idx: self.last_idx,
-                    calories,
-                });
-            } else {
-                calories += amount.parse::<i32>().unwrap();
-            }
-        }
-        if calories > 0 {
-            return Some(Elf {
-                idx: self.last_idx + 1,
-                calories,
-            });
-        }
-        None
+    if cli.stats
+        || cli.largest_item
+        || cli.most_items
+        || cli.over.is_some()
+        || cli.ranking.is_some()
+    {
+        let elves: Vec<Elf> = grouped_elves(cli.input.reader().or_exit(), grouping)
+            .collect::<Result<_, _>>()
+            .or_exit();
+        query(&cli, &elves);
+        return;
     }
-}
 
-fn elves(file_path: String) -> Result<Elves, Error> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
+    let k = cli.top.unwrap_or(3);
+    let top = match (&cli.input, cli.threads) {
+        (Input::Path(path), Some(threads)) => top_elves_mapped(path, k, grouping, threads),
+        _ => top_elves(grouped_elves(cli.input.reader().or_exit(), grouping), k),
+    }
+    .or_exit();
+    let calories = |n| total_calories(top.iter().take(n)).or_exit();
 
-    Ok(Elves {
-        buffer: reader.lines(),
-        last_idx: 0,
-    })
+    match cli.top {
+        Some(k) => println!("The top {k} elves have {} calories.", calories(k)),
+        None => {
+            println!("The top elf has {} calories.", calories(1));
+            println!("The top 3 elves have {} calories.", calories(3));
+        }
+    }
 }
 
-fn main() {
-    let file_path = env::args().nth(1).expect("File path missing.");
-
-    let elves = elves(file_path).unwrap_or_else(|err| {
-        eprintln!("Problem parsing arguments: {err}");
-        std::process::exit(1);
-    });
-
-    let mut elves: Vec<Elf> = elves.collect();
-    elves.sort_by_key(|e| e.calories);
-
-    println!(
-        "The top elf has {} calories.",
-        elves[elves.len() - 1].calories
-    );
-
-    println!(
-        "The top 3 elves have {:?} calories.",
-        &elves[elves.len() - 3..]
-            .into_iter()
-            .map(|e| e.calories)
-            .sum::<i32>()
-    )
+/// Answer the question asked on the command line about all elves.
+fn query(cli: &Cli, elves: &[Elf]) {
+    if cli.stats {
+        let percentiles: Vec<usize> = cli.percentiles.iter().map(|&p| p as usize).collect();
+        match Stats::new(elves, &percentiles, cli.bins).or_exit() {
+            Some(stats) => print!("{stats}"),
+            None => println!("No elves."),
+        }
+    }
+    if cli.largest_item {
+        match largest_item(elves) {
+            Some((elf, item)) => println!(
+                "Elf {} carries the largest item with {item} calories.",
+                elf.idx
+            ),
+            None => println!("No items."),
+        }
+    }
+    if cli.most_items {
+        match most_items(elves) {
+            Some(elf) => println!(
+                "Elf {} carries the most items, {} of them.",
+                elf.idx,
+                elf.items.len()
+            ),
+            None => println!("No elves."),
+        }
+    }
+    if let Some(calories) = cli.over {
+        write_list(&mut io::stdout().lock(), carrying_over(elves, calories)).or_exit();
+    }
+    if let Some(export) = cli.ranking {
+        let mut ranks = ranking(elves, cli.ties).or_exit();
+        ranks.truncate(cli.top.unwrap_or(ranks.len()));
+        match export {
+            Export::Csv => {
+                let mut writer = csv::Writer::from_writer(io::stdout().lock());
+                for rank in &ranks {
+                    writer.serialize(rank).or_exit();
+                }
+                writer.flush().or_exit();
+            }
+            Export::Json => println!("{}", serde_json::to_string(&ranks).unwrap()),
+        }
+    }
 }
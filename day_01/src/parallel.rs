@@ -0,0 +1,171 @@
+use crate::{grouped_elves, top::top_k_by_key, Elf, Error, Grouping};
+use aoc_common::Pos;
+use memmap2::Mmap;
+use std::fs::File;
+use std::path::Path;
+use std::thread;
+
+/// What one thread found in its chunk of the calorie list.
+struct Chunk {
+    /// The `k` elves of the chunk carrying the most, numbered from 1.
+    top: Result<Vec<Elf>, Error>,
+    /// Number of elves numbered in the chunk.
+    elves: i32,
+    /// Number of lines of the chunk.
+    lines: usize,
+}
+
+/// Split the list into about `n` chunks, each one ending directly after a
+/// blank line, so no elf spans two chunks.
+fn split(bytes: &[u8], n: usize) -> Vec<&[u8]> {
+    let mut chunks = vec![];
+    let mut start = 0;
+    for i in 1..n {
+        let target = (bytes.len() * i / n).max(start);
+        let end = match bytes[target..].windows(2).position(|w| w == b"\n\n") {
+            Some(offset) => target + offset + 2,
+            None => break,
+        };
+        if end < bytes.len() {
+            chunks.push(&bytes[start..end]);
+            start = end;
+        }
+    }
+    chunks.push(&bytes[start..]);
+    chunks
+}
+
+fn top_of_chunk(bytes: &[u8], k: usize, grouping: Grouping) -> Chunk {
+    let mut elves = grouped_elves(bytes, grouping);
+    let top = top_k_by_key(elves.by_ref(), k, |elf| elf.calories);
+    Chunk {
+        top,
+        elves: elves.last_idx,
+        lines: bytes.iter().filter(|&&b| b == b'\n').count(),
+    }
+}
+
+/// The `k` elves carrying the most calories, exactly like [`crate::top_elves`],
+/// but splitting the list up among `threads` threads.
+pub fn top_elves_in(
+    bytes: &[u8],
+    k: usize,
+    grouping: Grouping,
+    threads: usize,
+) -> Result<Vec<Elf>, Error> {
+    let chunks = split(bytes, threads.max(1));
+    let chunks: Vec<Chunk> = thread::scope(|scope| {
+        let handles: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| scope.spawn(move || top_of_chunk(chunk, k, grouping)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    // renumber the elves and lines of every chunk as if read in one go
+    let mut top = vec![];
+    let (mut elves, mut lines) = (0, 0);
+    for chunk in chunks {
+        let origin = Pos::new(lines + 1, 1);
+        match chunk.top {
+            Ok(chunk_top) => top.extend(chunk_top.into_iter().map(|elf| Elf {
+                idx: elf.idx + elves,
+                ..elf
+            })),
+            Err(err) => return Err(err.offset(origin, elves)),
+        }
+        elves += chunk.elves;
+        lines += chunk.lines;
+    }
+
+    top_k_by_key(top.into_iter().map(Ok), k, |elf| elf.calories)
+}
+
+/// The `k` elves carrying the most calories of the list in the file,
+/// memory-mapping it and splitting it up among `threads` threads.
+pub fn top_elves_mapped(
+    path: &Path,
+    k: usize,
+    grouping: Grouping,
+    threads: usize,
+) -> Result<Vec<Elf>, Error> {
+    let file = File::open(path)?;
+    if file.metadata()?.len() == 0 {
+        return Ok(vec![]);
+    }
+    // SAFETY: the list must not be changed while it is being read, as with
+    // any other way of reading it.
+    let map = unsafe { Mmap::map(&file)? };
+    top_elves_in(&map, k, grouping, threads)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::top_elves;
+
+    fn list() -> String {
+        let mut list = String::from("\n");
+        for i in 0..500 {
+            for j in 0..i % 4 {
+                list.push_str(&format!("{}\n", (i * 37 + j * 11) % 1000));
+            }
+            list.push_str(if i % 7 == 0 { "\n\n" } else { "\n" });
+        }
+        list
+    }
+
+    #[test]
+    fn same_as_sequential() {
+        let list = list();
+        for collapse_blank_runs in [true, false] {
+            for keep_empty in [true, false] {
+                let grouping = Grouping {
+                    collapse_blank_runs,
+                    keep_empty,
+                };
+                let expected = top_elves(grouped_elves(list.as_bytes(), grouping), 20).unwrap();
+                for threads in [1, 2, 3, 8, 64] {
+                    let top = top_elves_in(list.as_bytes(), 20, grouping, threads).unwrap();
+                    assert_eq!(top, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn split_at_blank_lines() {
+        let list = list();
+        let chunks = split(list.as_bytes(), 8);
+
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.concat(), list.as_bytes());
+        for chunk in &chunks[..chunks.len() - 1] {
+            assert!(chunk.ends_with(b"\n\n"));
+        }
+    }
+
+    #[test]
+    fn errors_as_sequential() {
+        let list = list() + "12\nx\n";
+        let err = top_elves_in(list.as_bytes(), 3, Grouping::default(), 4).unwrap_err();
+        let expected =
+            top_elves(grouped_elves(list.as_bytes(), Grouping::default()), 3).unwrap_err();
+
+        assert_eq!(err.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn overflow_as_sequential() {
+        let list = list() + &format!("{}\n1\n", u64::MAX);
+        let err = top_elves_in(list.as_bytes(), 3, Grouping::default(), 4).unwrap_err();
+        let expected =
+            top_elves(grouped_elves(list.as_bytes(), Grouping::default()), 3).unwrap_err();
+
+        assert!(matches!(err, Error::Overflow { .. }));
+        assert_eq!(err.to_string(), expected.to_string());
+    }
+}
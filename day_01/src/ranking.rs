@@ -0,0 +1,106 @@
+use crate::{total_calories, Elf, Error};
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::str::FromStr;
+
+/// How elves carrying the same amount of calories are ranked. Either way, they
+/// are listed in the order of the list.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Ties {
+    /// Share a rank, skipping the ranks after them ("1224").
+    #[default]
+    Competition,
+    /// Share a rank, not skipping any ranks ("1223").
+    Dense,
+    /// Get ranks of their own, in the order of the list ("1234").
+    Ordinal,
+}
+
+impl FromStr for Ties {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "competition" => Ok(Self::Competition),
+            "dense" => Ok(Self::Dense),
+            "ordinal" => Ok(Self::Ordinal),
+            _ => Err(format!("Invalid tie rule: {s}")),
+        }
+    }
+}
+
+/// Place of one elf in the ranking.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Rank {
+    pub rank: usize,
+    pub idx: i32,
+    pub calories: u64,
+    /// Fraction of the calories carried by all elves.
+    pub share: f64,
+}
+
+/// Rank the elves by the calories they carry, the most first.
+pub fn ranking(elves: &[Elf], ties: Ties) -> Result<Vec<Rank>, Error> {
+    let total = total_calories(elves)?;
+    let mut sorted: Vec<&Elf> = elves.iter().collect();
+    sorted.sort_by_key(|elf| (Reverse(elf.calories), elf.idx));
+
+    let mut ranks: Vec<Rank> = Vec::with_capacity(sorted.len());
+    for (i, elf) in sorted.into_iter().enumerate() {
+        let rank = match ranks.last() {
+            Some(last) if ties != Ties::Ordinal && last.calories == elf.calories => last.rank,
+            Some(last) if ties == Ties::Dense => last.rank + 1,
+            _ => i + 1,
+        };
+        ranks.push(Rank {
+            rank,
+            idx: elf.idx,
+            calories: elf.calories,
+            share: match total {
+                0 => 0.0,
+                _ => elf.calories as f64 / total as f64,
+            },
+        });
+    }
+    Ok(ranks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elves;
+
+    fn ranks(ties: Ties) -> Vec<(usize, i32)> {
+        let list = "5\n\n3\n\n5\n\n1\n\n3\n";
+        let elves: Vec<Elf> = elves(list.as_bytes()).collect::<Result<_, _>>().unwrap();
+        ranking(&elves, ties)
+            .unwrap()
+            .iter()
+            .map(|rank| (rank.rank, rank.idx))
+            .collect()
+    }
+
+    #[test]
+    fn ties() {
+        let order = [1, 3, 2, 5, 4];
+        let with_ranks = |ranks: [usize; 5]| ranks.into_iter().zip(order).collect::<Vec<_>>();
+
+        assert_eq!(ranks(Ties::Competition), with_ranks([1, 1, 3, 3, 5]));
+        assert_eq!(ranks(Ties::Dense), with_ranks([1, 1, 2, 2, 3]));
+        assert_eq!(ranks(Ties::Ordinal), with_ranks([1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    fn share() {
+        let elves: Vec<Elf> = elves("1\n\n3\n".as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let shares: Vec<f64> = ranking(&elves, Ties::default())
+            .unwrap()
+            .iter()
+            .map(|rank| rank.share)
+            .collect();
+
+        assert_eq!(shares, vec![0.75, 0.25]);
+    }
+}
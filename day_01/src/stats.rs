@@ -0,0 +1,217 @@
+use crate::{Elf, Error};
+use std::fmt;
+
+/// Width of the longest bar of the histogram.
+const BAR_WIDTH: usize = 40;
+
+/// Summary of one number counted for every elf.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Summary {
+    pub count: usize,
+    pub total: u64,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    /// Nearest-rank percentiles, each with its value.
+    pub percentiles: Vec<(usize, u64)>,
+}
+
+impl Summary {
+    /// Summarize the values, or `None` if there are none.
+    pub fn new(mut values: Vec<u64>, percentiles: &[usize]) -> Result<Option<Self>, Error> {
+        values.sort_unstable();
+        let count = values.len();
+        let (&min, &max) = match (values.first(), values.last()) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return Ok(None),
+        };
+
+        let total = values
+            .iter()
+            .try_fold(0u64, |total, &value| total.checked_add(value))
+            .ok_or(Error::TotalOverflow)?;
+        let mean = total as f64 / count as f64;
+        let variance = values
+            .iter()
+            .map(|&value| (value as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+        let median = match count % 2 {
+            0 => (values[count / 2 - 1] as f64 + values[count / 2] as f64) / 2.0,
+            _ => values[count / 2] as f64,
+        };
+
+        Ok(Some(Self {
+            count,
+            total,
+            min,
+            max,
+            mean,
+            median,
+            std_dev: variance.sqrt(),
+            percentiles: percentiles
+                .iter()
+                .map(|&p| (p, percentile(&values, p)))
+                .collect(),
+        }))
+    }
+}
+
+/// Nearest-rank percentile of sorted values.
+fn percentile(sorted: &[u64], p: usize) -> u64 {
+    let rank = (p * sorted.len()).div_ceil(100);
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "  total    {}", self.total)?;
+        writeln!(f, "  min      {}", self.min)?;
+        writeln!(f, "  max      {}", self.max)?;
+        writeln!(f, "  mean     {:.2}", self.mean)?;
+        writeln!(f, "  median   {:.1}", self.median)?;
+        writeln!(f, "  std dev  {:.2}", self.std_dev)?;
+        for (p, value) in &self.percentiles {
+            writeln!(f, "  p{p:<7} {value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Number of values falling into each of a few bins of equal width.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Histogram {
+    /// Lower bound of the first bin.
+    pub from: u64,
+    /// Width of every bin.
+    pub width: u64,
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    /// Spread the values over at most `bins` bins, or `None` if there are none.
+    pub fn new(values: &[u64], bins: usize) -> Option<Self> {
+        let min = *values.iter().min()?;
+        let max = *values.iter().max()?;
+        let width = ((max - min) / bins.max(1) as u64).saturating_add(1);
+
+        let mut counts = vec![0; ((max - min) / width + 1) as usize];
+        for value in values {
+            counts[((value - min) / width) as usize] += 1;
+        }
+
+        Some(Self {
+            from: min,
+            width,
+            counts,
+        })
+    }
+
+    /// Upper bound of the `i`th bin, as far as it fits.
+    fn upper(&self, i: usize) -> u64 {
+        (self.width * i as u64).saturating_add(self.from.saturating_add(self.width - 1))
+    }
+}
+
+/// Draws one bar of `#` per bin, the fullest one `BAR_WIDTH` long.
+impl fmt::Display for Histogram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fullest = self.counts.iter().max().copied().unwrap_or(0).max(1);
+        let last = self.upper(self.counts.len() - 1);
+        let digits = last.to_string().len();
+
+        for (i, &count) in self.counts.iter().enumerate() {
+            let from = self.from + self.width * i as u64;
+            let bar = "#".repeat((count * BAR_WIDTH).div_ceil(fullest));
+            writeln!(
+                f,
+                "  {from:>digits$} - {:>digits$} | {bar:<BAR_WIDTH$} {count}",
+                self.upper(i)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Statistics about the calories and items the elves carry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub calories: Summary,
+    pub items: Summary,
+    pub histogram: Histogram,
+}
+
+impl Stats {
+    /// Statistics about the elves, or `None` if there are none.
+    pub fn new(elves: &[Elf], percentiles: &[usize], bins: usize) -> Result<Option<Self>, Error> {
+        let calories: Vec<u64> = elves.iter().map(|elf| elf.calories).collect();
+        let items = elves.iter().map(|elf| elf.items.len() as u64).collect();
+
+        let Some(histogram) = Histogram::new(&calories, bins) else {
+            return Ok(None);
+        };
+        Ok(Summary::new(calories, percentiles)?
+            .zip(Summary::new(items, percentiles)?)
+            .map(|(calories, items)| Self {
+                calories,
+                items,
+                histogram,
+            }))
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} elves", self.calories.count)?;
+        writeln!(f, "Calories per elf:")?;
+        write!(f, "{}", self.calories)?;
+        writeln!(f, "Items per elf:")?;
+        write!(f, "{}", self.items)?;
+        writeln!(f, "Calories histogram:")?;
+        write!(f, "{}", self.histogram)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elves;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        let elves: Vec<Elf> = elves(EXAMPLE.as_bytes()).collect::<Result<_, _>>().unwrap();
+        let stats = Stats::new(&elves, &[50, 90], 3).unwrap().unwrap();
+
+        assert_eq!(stats.calories.count, 5);
+        assert_eq!(stats.calories.total, 55000);
+        assert_eq!(stats.calories.mean, 11000.0);
+        assert_eq!(stats.calories.median, 10000.0);
+        assert!((stats.calories.std_dev - 6985.70).abs() < 0.01);
+        assert_eq!(stats.calories.percentiles, vec![(50, 10000), (90, 24000)]);
+
+        assert_eq!(stats.items.total, 10);
+        assert_eq!((stats.items.min, stats.items.max), (1, 3));
+        assert_eq!(stats.items.median, 2.0);
+
+        assert_eq!(stats.histogram.from, 4000);
+        assert_eq!(stats.histogram.width, 6667);
+        assert_eq!(stats.histogram.counts, vec![3, 1, 1]);
+    }
+
+    #[test]
+    fn extremes() {
+        let histogram = Histogram::new(&[0, u64::MAX], 1).unwrap();
+
+        assert_eq!(histogram.counts, vec![1, 1]);
+        assert!(histogram.to_string().contains(&u64::MAX.to_string()));
+    }
+
+    #[test]
+    fn no_elves() {
+        assert_eq!(Stats::new(&[], &[50], 10).unwrap(), None);
+    }
+}
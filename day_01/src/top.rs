@@ -0,0 +1,64 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// An item ordered by its key alone. Of equal keys, the one seen first is the
+/// larger, so it is the one kept.
+struct Keyed<K, T> {
+    key: K,
+    seq: usize,
+    item: T,
+}
+
+impl<K: Ord, T> Ord for Keyed<K, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key
+            .cmp(&other.key)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl<K: Ord, T> PartialOrd for Keyed<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> PartialEq for Keyed<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<K: Ord, T> Eq for Keyed<K, T> {}
+
+/// The `k` items with the largest keys, the largest first, stopping at the
+/// first error. Only `k` items are held in memory at any time, so the items
+/// can be streamed from an input of any size.
+pub fn top_k_by_key<T, K: Ord, E>(
+    items: impl IntoIterator<Item = Result<T, E>>,
+    k: usize,
+    key: impl Fn(&T) -> K,
+) -> Result<Vec<T>, E> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for (seq, item) in items.into_iter().enumerate() {
+        let item = item?;
+        let keyed = Reverse(Keyed {
+            key: key(&item),
+            seq,
+            item,
+        });
+
+        if heap.len() < k {
+            heap.push(keyed);
+        } else if heap.peek().is_some_and(|smallest| keyed < *smallest) {
+            heap.pop();
+            heap.push(keyed);
+        }
+    }
+
+    Ok(heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(keyed)| keyed.item)
+        .collect())
+}
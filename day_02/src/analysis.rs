@@ -0,0 +1,134 @@
+use crate::{Choice, Game, Outcome};
+use std::fmt;
+
+/// Patterns in a list of games: how the opponent chooses, and how the games
+/// end.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Analysis {
+    pub games: usize,
+    /// How often the opponent chose every choice, in the order of
+    /// [`Choice::ALL`].
+    pub frequencies: [usize; 3],
+    /// How often the opponent chose a choice (second index) right after
+    /// another (first index).
+    pub transitions: [[usize; 3]; 3],
+    /// How often the games ended in every outcome, in the order of
+    /// [`Outcome::ALL`].
+    pub outcomes: [usize; 3],
+    pub longest_win_streak: usize,
+    pub longest_loss_streak: usize,
+}
+
+impl Analysis {
+    pub fn new(games: &[Game]) -> Self {
+        let mut analysis = Self {
+            games: games.len(),
+            ..Default::default()
+        };
+
+        for game in games {
+            analysis.frequencies[game.other.index()] += 1;
+            analysis.outcomes[game.outcome.index()] += 1;
+        }
+        for pair in games.windows(2) {
+            analysis.transitions[pair[0].other.index()][pair[1].other.index()] += 1;
+        }
+
+        let streak = |outcome: Outcome| {
+            games
+                .split(|game| game.outcome != outcome)
+                .map(|streak| streak.len())
+                .max()
+                .unwrap_or(0)
+        };
+        analysis.longest_win_streak = streak(Outcome::Win);
+        analysis.longest_loss_streak = streak(Outcome::Loose);
+        analysis
+    }
+
+    /// Chance of the opponent choosing `to` right after `from`, if it ever
+    /// chose anything after `from`.
+    pub fn transition(&self, from: Choice, to: Choice) -> Option<f64> {
+        let row = &self.transitions[from.index()];
+        match row.iter().sum::<usize>() {
+            0 => None,
+            total => Some(row[to.index()] as f64 / total as f64),
+        }
+    }
+
+    /// Share of the games ending in the outcome.
+    pub fn rate(&self, outcome: Outcome) -> f64 {
+        match self.games {
+            0 => 0.0,
+            games => self.outcomes[outcome.index()] as f64 / games as f64,
+        }
+    }
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = |choice: Choice| format!("{choice:?}");
+        let percent = |share: f64| format!("{:6.1}%", share * 100.0);
+
+        writeln!(f, "{} games", self.games)?;
+        writeln!(f, "Opponent's choices:")?;
+        for choice in Choice::ALL {
+            let count = self.frequencies[choice.index()];
+            let share = count as f64 / self.games.max(1) as f64;
+            writeln!(f, "  {:<8} {count:>6} {}", name(choice), percent(share))?;
+        }
+
+        writeln!(f, "Opponent's next choice after:")?;
+        writeln!(
+            f,
+            "  {:<8} {:>7} {:>7} {:>7}",
+            "", "Rock", "Paper", "Scissor"
+        )?;
+        for from in Choice::ALL {
+            write!(f, "  {:<8}", name(from))?;
+            for to in Choice::ALL {
+                match self.transition(from, to) {
+                    Some(chance) => write!(f, " {}", percent(chance))?,
+                    None => write!(f, " {:>7}", "-")?,
+                }
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(f, "Outcomes:")?;
+        for (outcome, label) in Outcome::ALL.into_iter().zip(["Losses", "Draws", "Wins"]) {
+            let count = self.outcomes[outcome.index()];
+            writeln!(f, "  {label:<8} {count:>6} {}", percent(self.rate(outcome)))?;
+        }
+        writeln!(f, "Longest win streak:  {}", self.longest_win_streak)?;
+        writeln!(f, "Longest loss streak: {}", self.longest_loss_streak)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_guide, Column};
+
+    #[test]
+    fn example() {
+        let games = parse_guide(include_str!("../example.txt"), Column::Choice).unwrap();
+        let analysis = Analysis::new(&games);
+
+        assert_eq!(analysis.frequencies, [1, 1, 1]);
+        assert_eq!(analysis.outcomes, [1, 1, 1]);
+        assert_eq!(analysis.transition(Choice::Rock, Choice::Paper), Some(1.0));
+        assert_eq!(analysis.transition(Choice::Scissor, Choice::Rock), None);
+        assert_eq!(analysis.rate(Outcome::Win), 1.0 / 3.0);
+    }
+
+    #[test]
+    fn streaks() {
+        let games = parse_guide("A Y\nA Y\nA Z\nA Z\nA Z\nA X\nA Y\n", Column::Choice).unwrap();
+        let analysis = Analysis::new(&games);
+
+        assert_eq!(analysis.longest_win_streak, 2);
+        assert_eq!(analysis.longest_loss_streak, 3);
+        assert_eq!(analysis.transition(Choice::Rock, Choice::Rock), Some(1.0));
+    }
+}
@@ -0,0 +1,63 @@
+use aoc_common::Pos;
+use std::fmt;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    InvalidChoice { pos: Pos, token: String },
+    InvalidOutcome { pos: Pos, token: String },
+    UnreachableOutcome { pos: Pos, token: String },
+    MissingToken { pos: Pos },
+    UnexpectedToken { pos: Pos, token: String },
+    InvalidRules { reason: String },
+    ScoreOverflow { pos: Pos },
+}
+
+impl Error {
+    /// Move the error from the position in a single token or line to the
+    /// position in the whole input.
+    pub fn offset(self, origin: Pos) -> Self {
+        match self {
+            Self::InvalidChoice { pos, token } => Self::InvalidChoice {
+                pos: pos.offset(origin),
+                token,
+            },
+            Self::InvalidOutcome { pos, token } => Self::InvalidOutcome {
+                pos: pos.offset(origin),
+                token,
+            },
+            Self::UnreachableOutcome { pos, token } => Self::UnreachableOutcome {
+                pos: pos.offset(origin),
+                token,
+            },
+            Self::MissingToken { pos } => Self::MissingToken {
+                pos: pos.offset(origin),
+            },
+            Self::UnexpectedToken { pos, token } => Self::UnexpectedToken {
+                pos: pos.offset(origin),
+                token,
+            },
+            Self::InvalidRules { reason } => Self::InvalidRules { reason },
+            Self::ScoreOverflow { pos } => Self::ScoreOverflow {
+                pos: pos.offset(origin),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChoice { pos, token } => write!(f, "{pos}: invalid choice '{token}'"),
+            Self::InvalidOutcome { pos, token } => write!(f, "{pos}: invalid outcome '{token}'"),
+            Self::UnreachableOutcome { pos, token } => {
+                write!(f, "{pos}: no move ends in outcome '{token}'")
+            }
+            Self::MissingToken { pos } => write!(f, "{pos}: expected two columns"),
+            Self::UnexpectedToken { pos, token } => write!(f, "{pos}: unexpected '{token}'"),
+            Self::InvalidRules { reason } => write!(f, "invalid rules: {reason}"),
+            Self::ScoreOverflow { pos } => write!(f, "{pos}: score too high"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
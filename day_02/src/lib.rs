@@ -0,0 +1,391 @@
+pub mod analysis;
+mod error;
+pub mod repl;
+pub mod rules;
+pub mod solver;
+pub mod strategy;
+
+pub use error::Error;
+
+use aoc_common::{columns, phase, Answer, Pos, Solution};
+use log::debug;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Choice {
+    Rock = 1,
+    Paper = 2,
+    Scissor = 3,
+}
+
+impl Choice {
+    pub const ALL: [Choice; 3] = [Self::Rock, Self::Paper, Self::Scissor];
+
+    /// Position of the choice in [`Choice::ALL`].
+    pub const fn index(&self) -> usize {
+        *self as usize - 1
+    }
+
+    /// My choice ending in the outcome against the opponent's, as looked up
+    /// in [`Outcome::TABLE`].
+    pub fn from_outcome(other: Choice, outcome: Outcome) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|&mine| Outcome::from_choices(mine, other) == outcome)
+            .unwrap()
+    }
+
+    /// The choice this one wins against.
+    pub fn beats(&self) -> Self {
+        Self::from_outcome(*self, Outcome::Loose)
+    }
+}
+
+impl FromStr for Choice {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "X" => Ok(Choice::Rock),
+            "B" | "Y" => Ok(Choice::Paper),
+            "C" | "Z" => Ok(Choice::Scissor),
+            _ => Err(Error::InvalidChoice {
+                pos: Pos::default(),
+                token: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Writes the letter of the opponent's column, or with `{:#}` the letter of
+/// my column.
+impl fmt::Display for Choice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (other, mine) = match self {
+            Self::Rock => ("A", "X"),
+            Self::Paper => ("B", "Y"),
+            Self::Scissor => ("C", "Z"),
+        };
+        write!(f, "{}", if f.alternate() { mine } else { other })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Loose = 0,
+    Draw = 3,
+    Win = 6,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Self::Loose, Self::Draw, Self::Win];
+
+    /// Position of the outcome in [`Outcome::ALL`].
+    pub fn index(&self) -> usize {
+        *self as usize / 3
+    }
+
+    /// Outcome of every game, by my choice and then the opponent's, both in
+    /// the order of [`Choice::ALL`].
+    const TABLE: [[Outcome; 3]; 3] = {
+        use Outcome::{Draw, Loose, Win};
+        [
+            // Rock  Paper  Scissor
+            [Draw, Loose, Win], // Rock
+            [Win, Draw, Loose], // Paper
+            [Loose, Win, Draw], // Scissor
+        ]
+    };
+
+    pub fn from_choices(mine: Choice, other: Choice) -> Self {
+        Self::TABLE[mine.index()][other.index()]
+    }
+}
+
+// Every game won by one side is lost by the other, and a draw for both. Every
+// choice wins against exactly one other, and rock against scissors, which
+// leaves only the rules of the puzzle, with every outcome reached by exactly
+// one choice.
+const _: () = {
+    let (rock, scissor) = (Choice::Rock.index(), Choice::Scissor.index());
+    assert!(Outcome::TABLE[rock][scissor] as u8 == Outcome::Win as u8);
+
+    let mut mine = 0;
+    while mine < 3 {
+        let mut wins = 0;
+        let mut other = 0;
+        while other < 3 {
+            if Outcome::TABLE[mine][other] as u8 == Outcome::Win as u8 {
+                wins += 1;
+            }
+            let outcomes = Outcome::TABLE[mine][other] as u8 + Outcome::TABLE[other][mine] as u8;
+            assert!(outcomes == Outcome::Win as u8 + Outcome::Loose as u8);
+            assert!((mine == other) == (Outcome::TABLE[mine][other] as u8 == Outcome::Draw as u8));
+            other += 1;
+        }
+        assert!(wins == 1);
+        mine += 1;
+    }
+};
+
+impl FromStr for Outcome {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Self::Loose),
+            "Y" => Ok(Self::Draw),
+            "Z" => Ok(Self::Win),
+            _ => Err(Error::InvalidOutcome {
+                pos: Pos::default(),
+                token: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Loose => write!(f, "X"),
+            Self::Draw => write!(f, "Y"),
+            Self::Win => write!(f, "Z"),
+        }
+    }
+}
+
+/// Split a line of the strategy guide into its two columns.
+fn split_line<A, B>(
+    s: &str,
+    first: impl Fn(&str) -> Result<A, Error>,
+    second: impl Fn(&str) -> Result<B, Error>,
+) -> Result<(A, B), Error> {
+    let mut parts = columns(s, " ");
+    let missing = || Error::MissingToken {
+        pos: Pos::new(1, s.len() + 1),
+    };
+
+    let (column, token) = parts.next().ok_or_else(missing)?;
+    let first = first(token).map_err(|err| err.offset(Pos::new(1, column)))?;
+    let (column, token) = parts.next().ok_or_else(missing)?;
+    let second = second(token).map_err(|err| err.offset(Pos::new(1, column)))?;
+
+    match parts.next() {
+        Some((column, token)) => Err(Error::UnexpectedToken {
+            pos: Pos::new(1, column),
+            token: token.to_string(),
+        }),
+        None => Ok((first, second)),
+    }
+}
+
+/// How the second column of the strategy guide is read.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Column {
+    /// My choice, as assumed in part 1.
+    Choice,
+    /// The outcome the game has to end in, as explained in part 2.
+    Outcome,
+}
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "choice" => Ok(Self::Choice),
+            "outcome" => Ok(Self::Outcome),
+            _ => Err(format!("Invalid column: {s}")),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    pub other: Choice,
+    pub mine: Choice,
+    pub outcome: Outcome,
+}
+
+impl Game {
+    pub fn new(other: Choice, mine: Choice) -> Self {
+        Self {
+            other,
+            mine,
+            outcome: Outcome::from_choices(mine, other),
+        }
+    }
+
+    pub fn parse(s: &str, column: Column) -> Result<Self, Error> {
+        match column {
+            Column::Choice => s.parse(),
+            Column::Outcome => {
+                let (other, outcome) = split_line(s, Choice::from_str, Outcome::from_str)?;
+                Ok(Self {
+                    other,
+                    mine: Choice::from_outcome(other, outcome),
+                    outcome,
+                })
+            }
+        }
+    }
+
+    pub fn score(&self) -> u32 {
+        self.outcome as u32 + self.mine as u32
+    }
+}
+
+/// Parses a line of the strategy guide with my choice in the second column.
+impl FromStr for Game {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (other, mine) = split_line(s, Choice::from_str, Choice::from_str)?;
+        Ok(Self::new(other, mine))
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:#}", self.other, self.mine)
+    }
+}
+
+/// Parse every line of the strategy guide, reading the second column as given.
+pub fn parse_guide(input: &str, column: Column) -> Result<Vec<Game>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| Game::parse(line, column).map_err(|err| err.offset(Pos::new(i + 1, 1))))
+        .collect()
+}
+
+pub fn total_score(input: &str, column: Column) -> Result<u32, Error> {
+    let games = phase("parse", || parse_guide(input, column))?;
+
+    Ok(phase("solve", || {
+        let mut total_score = 0;
+        for game in games {
+            total_score += game.score();
+
+            debug!("{game:?} => {:?}", game.score());
+        }
+        total_score
+    }))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        Ok(total_score(input, Column::Choice)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        Ok(total_score(input, Column::Outcome)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(Day02.part1(EXAMPLE).unwrap(), "15");
+        assert_eq!(Day02.part2(EXAMPLE).unwrap(), "12");
+    }
+
+    #[test]
+    fn beats() {
+        assert_eq!(Choice::Rock.beats(), Choice::Scissor);
+        assert_eq!(Choice::Paper.beats(), Choice::Rock);
+        assert_eq!(Choice::Scissor.beats(), Choice::Paper);
+    }
+
+    #[test]
+    fn from_choices() {
+        use Choice::{Paper, Rock, Scissor};
+        let expected = [
+            (Rock, Rock, Outcome::Draw),
+            (Rock, Paper, Outcome::Loose),
+            (Rock, Scissor, Outcome::Win),
+            (Paper, Rock, Outcome::Win),
+            (Paper, Paper, Outcome::Draw),
+            (Paper, Scissor, Outcome::Loose),
+            (Scissor, Rock, Outcome::Loose),
+            (Scissor, Paper, Outcome::Win),
+            (Scissor, Scissor, Outcome::Draw),
+        ];
+
+        for (mine, other, outcome) in expected {
+            assert_eq!(
+                Outcome::from_choices(mine, other),
+                outcome,
+                "{mine:?} vs {other:?}"
+            );
+            assert_eq!(mine.beats() == other, outcome == Outcome::Win);
+        }
+    }
+
+    #[test]
+    fn from_outcome() {
+        use Choice::{Paper, Rock, Scissor};
+        let expected = [
+            (Rock, Outcome::Loose, Scissor),
+            (Rock, Outcome::Draw, Rock),
+            (Rock, Outcome::Win, Paper),
+            (Paper, Outcome::Loose, Rock),
+            (Paper, Outcome::Draw, Paper),
+            (Paper, Outcome::Win, Scissor),
+            (Scissor, Outcome::Loose, Paper),
+            (Scissor, Outcome::Draw, Scissor),
+            (Scissor, Outcome::Win, Rock),
+        ];
+
+        for (other, outcome, mine) in expected {
+            assert_eq!(
+                Choice::from_outcome(other, outcome),
+                mine,
+                "{outcome:?} against {other:?}"
+            );
+            assert_eq!(Outcome::from_choices(mine, other), outcome);
+        }
+    }
+
+    #[test]
+    fn score() {
+        let game = Game::parse("A Y", Column::Choice).unwrap();
+        assert_eq!(game.outcome, Outcome::Win);
+        assert_eq!(game.score(), 8);
+
+        let game = Game::parse("A Y", Column::Outcome).unwrap();
+        assert_eq!(game.mine, Choice::Rock);
+        assert_eq!(game.score(), 4);
+    }
+
+    #[test]
+    fn round_trip() {
+        for line in EXAMPLE.lines() {
+            assert_eq!(line.parse::<Game>().unwrap().to_string(), line);
+        }
+    }
+
+    #[test]
+    fn invalid_choice() {
+        assert_eq!(
+            total_score("A Y\nB Q\n", Column::Choice),
+            Err(Error::InvalidChoice {
+                pos: Pos::new(2, 3),
+                token: "Q".to_string(),
+            })
+        );
+    }
+}
@@ -1,124 +1,185 @@
-use std::env;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Error, Lines};
-
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-enum Choice {
-    Rock = 1,
-    Paper = 2,
-    Scissor = 3,
+use aoc_common::{Input, InputError, OrExit, Solution};
+use clap::Parser;
+use day_02::analysis::Analysis;
+use day_02::repl::play;
+use day_02::rules::{Preset, Rules};
+use day_02::solver::{games, hit_target, optimize, parse_opponent, Goal};
+use day_02::strategy::{
+    named, tournament, Always, FrequencyCounter, Guide, Markov, Random, Strategy,
+};
+use day_02::{parse_guide, Choice, Column, Day02, Outcome};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Scores the strategy guide for the rock paper scissors tournament.
+#[derive(Parser)]
+struct Cli {
+    /// Strategy guide, `-` for stdin.
+    input: Option<Input>,
+    /// Play against a strategy in the terminal instead: `random`,
+    /// `always-rock`, `frequency` or `markov`.
+    #[arg(long, value_name = "STRATEGY")]
+    play: Option<String>,
+    /// Let the guide play against other strategies instead, round robin.
+    #[arg(long)]
+    tournament: bool,
+    /// Games every two strategies play against each other, by default as
+    /// many as the guide has lines.
+    #[arg(long)]
+    rounds: Option<usize>,
+    /// Seed of the random strategy.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Score the guide with the moves, tokens and scores of a rules file
+    /// instead, like `day_02/rules.toml`.
+    #[arg(long, value_name = "FILE")]
+    rules: Option<PathBuf>,
+    /// Score the guide with the rules of another game instead: `rps` or
+    /// `rpsls` (rock paper scissors lizard Spock).
+    #[arg(long, conflicts_with = "rules")]
+    game: Option<Preset>,
+    /// Score part 1 with every way of reading X, Y and Z as the moves instead,
+    /// the highest scoring first.
+    #[arg(long)]
+    mappings: bool,
+    /// Write out a guide for the opponent's column scoring exactly this
+    /// instead.
+    #[arg(long, value_name = "SCORE")]
+    target: Option<u32>,
+    /// Write out a guide for the opponent's column scoring the most or the
+    /// least instead: `max` or `min`.
+    #[arg(long)]
+    optimize: Option<Goal>,
+    /// Win at most this many games with `--optimize`.
+    #[arg(long, value_name = "N")]
+    max_wins: Option<usize>,
+    /// Report patterns in the opponent's choices and the outcomes instead.
+    #[arg(long)]
+    analyze: bool,
+    /// How to read the second column with `--analyze`, `--rules` or `--game`:
+    /// as my `choice`, or as the `outcome`. By default, `--analyze` reads it as
+    /// my choice, and the rules score the guide both ways.
+    #[arg(long)]
+    column: Option<Column>,
 }
 
-impl Choice {
-    fn from_str(s: &str) -> Self {
-        match s {
-            "A" | "X" => Choice::Rock,
-            "B" | "Y" => Choice::Paper,
-            "C" | "Z" => Choice::Scissor,
-            _ => panic!("Invalid character for Choice: {s}"),
-        }
-    }
+fn main() {
+    let cli = Cli::parse();
 
-    fn from_outcome(other: Choice, outcome: Outcome) -> Self {
-        match outcome {
-            Outcome::Loose => other.beats(),
-            Outcome::Draw => other,
-            Outcome::Win => other.beats().beats(),
-        }
+    if let Some(name) = &cli.play {
+        let mut strategy = named(name, cli.seed).or_exit();
+        play(io::stdin().lock(), &mut io::stdout(), strategy.as_mut()).or_exit();
+        return;
     }
 
-    fn beats(&self) -> Self {
-        match *self {
-            Self::Rock => Self::Scissor,
-            Self::Paper => Self::Rock,
-            Self::Scissor => Self::Paper,
+    let contents = cli
+        .input
+        .ok_or(InputError::Missing)
+        .and_then(|input| input.read_to_string())
+        .or_exit();
+
+    if cli.tournament {
+        let games = parse_guide(&contents, Column::Choice).or_exit();
+        let mut strategies: Vec<Box<dyn Strategy>> = vec![
+            Box::new(Guide::new(
+                "guide",
+                games.iter().map(|game| game.mine).collect(),
+            )),
+            Box::new(Guide::new(
+                "opponent",
+                games.iter().map(|game| game.other).collect(),
+            )),
+            Box::new(Random::new(cli.seed)),
+            Box::new(Always(Choice::Rock)),
+            Box::new(FrequencyCounter::default()),
+            Box::new(Markov::default()),
+        ];
+        if games.is_empty() {
+            strategies.drain(..2);
         }
-    }
-}
-
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-enum Outcome {
-    Loose = 0,
-    Draw = 3,
-    Win = 6,
-}
 
-impl Outcome {
-    fn from_str(s: &str) -> Self {
-        match s {
-            "X" => Self::Loose,
-            "Y" => Self::Draw,
-            "Z" => Self::Win,
-            _ => panic!("Invalid character for Choice: {s}"),
+        for standing in tournament(&mut strategies, cli.rounds.unwrap_or(games.len())) {
+            println!("{standing}");
         }
+        return;
     }
 
-    fn from_choices(mine: Choice, other: Choice) -> Self {
-        match mine {
-            _ if mine.beats() == other => Self::Win,
-            _ if other.beats() == mine => Self::Loose,
-            other => Self::Draw,
-        }
+    if cli.analyze {
+        let games = parse_guide(&contents, cli.column.unwrap_or(Column::Choice)).or_exit();
+        print!("{}", Analysis::new(&games));
+        return;
     }
-}
-
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-struct Game {
-    other: Choice,
-    mine: Choice,
-    outcome: Outcome,
-}
 
-impl Game {
-    fn from_str1(s: String) -> Self {
-        let mut parts = s.split(" ");
-        let other = Choice::from_str(parts.next().unwrap());
-        let mine = Choice::from_str(parts.next().unwrap());
-        Self {
-            other,
-            mine,
-            outcome: Outcome::from_choices(mine, other),
+    if cli.target.is_some() || cli.optimize.is_some() {
+        let other = parse_opponent(&contents).or_exit();
+        let mine = match (cli.target, cli.optimize) {
+            (Some(target), _) => hit_target(&other, target).unwrap_or_else(|| {
+                eprintln!("No guide scores exactly {target}.");
+                std::process::exit(1);
+            }),
+            (None, Some(goal)) => optimize(
+                &other,
+                goal,
+                Outcome::Win,
+                cli.max_wins.unwrap_or(other.len()),
+            ),
+            (None, None) => unreachable!(),
+        };
+
+        for game in games(&other, &mine) {
+            println!("{game}");
         }
+        return;
     }
 
-    fn from_str2(s: String) -> Self {
-        let mut parts = s.split(" ");
-        let other = Choice::from_str(parts.next().unwrap());
-        let outcome = Outcome::from_str(parts.next().unwrap());
-        Self {
-            other,
-            mine: Choice::from_outcome(other, outcome),
-            outcome,
+    if cli.rules.is_some() || cli.game.is_some() || cli.mappings {
+        let rules: Rules = match &cli.rules {
+            Some(path) => fs::read_to_string(path)
+                .map_err(|err| format!("Problem reading {}: {err}", path.display()))
+                .or_exit()
+                .parse()
+                .or_exit(),
+            None => cli.game.unwrap_or_default().rules(),
+        };
+
+        if cli.mappings {
+            let mut scores: Vec<(String, u32)> = rules
+                .mappings(&["X", "Y", "Z"])
+                .or_exit()
+                .map(|(mapping, rules)| (mapping, rules.total_score(&contents, Column::Choice)))
+                .map(|(mapping, score)| (mapping, score.or_exit()))
+                .collect();
+            scores.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+            for (mapping, score) in scores {
+                println!("{mapping}: {score}");
+            }
+        } else {
+            // A guide is usually written for only one of the parts, so the
+            // other one failing does not keep it from being scored.
+            let parts = [(1, Column::Choice), (2, Column::Outcome)];
+            let mut scored = false;
+            for (part, column) in parts {
+                if cli.column.is_some_and(|only| only != column) {
+                    continue;
+                }
+                match rules.total_score(&contents, column) {
+                    Ok(score) => {
+                        println!("Total score part {part}: {score}.");
+                        scored = true;
+                    }
+                    Err(err) => eprintln!("Part {part}: {err}"),
+                }
+            }
+            if !scored {
+                std::process::exit(1);
+            }
         }
+        return;
     }
 
-    fn score(&self) -> u32 {
-        self.outcome as u32 + self.mine as u32
-    }
-}
-
-fn read_lines(file_path: String) -> Result<Lines<BufReader<File>>, Error> {
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
-
-    Ok(reader.lines())
-}
-
-fn main() {
-    let file_path = env::args().nth(1).expect("File path missing.");
-
-    let lines = read_lines(file_path).unwrap_or_else(|err| {
-        eprintln!("Problem opening file: {err}");
-        std::process::exit(1);
-    });
-
-    let mut total_score = 0;
-    for line in lines {
-        let game = Game::from_str2(line.unwrap());
-        total_score += game.score();
-
-        println!("{game:?} => {:?}", game.score());
-    }
+    let answers = Day02.answers(&contents).or_exit();
 
-    println!("Total score: {total_score}.")
+    println!("Total score part 1: {}.", answers.part1);
+    println!("Total score part 2: {}.", answers.part2);
 }
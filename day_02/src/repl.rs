@@ -0,0 +1,99 @@
+use crate::strategy::{Standing, Strategy};
+use crate::{Choice, Game, Outcome};
+use std::io::{self, BufRead, Write};
+
+/// The choice a human typed, `None` if it is not one.
+fn parse_choice(line: &str) -> Option<Choice> {
+    match line.to_ascii_lowercase().as_str() {
+        "r" | "rock" => Some(Choice::Rock),
+        "p" | "paper" => Some(Choice::Paper),
+        "s" | "scissor" | "scissors" => Some(Choice::Scissor),
+        _ => None,
+    }
+}
+
+/// Let a human play against the strategy, reading a choice per line until
+/// the end of the input or `q`, and telling how every game went.
+pub fn play(
+    input: impl BufRead,
+    output: &mut impl Write,
+    strategy: &mut dyn Strategy,
+) -> io::Result<(Standing, Standing)> {
+    strategy.reset();
+    let mut human = Standing {
+        name: "you".to_string(),
+        ..Default::default()
+    };
+    let mut computer = Standing {
+        name: strategy.name().to_string(),
+        ..Default::default()
+    };
+
+    let prompt = "Rock, paper or scissors? [r/p/s, q to quit] ";
+    write!(output, "{prompt}")?;
+    output.flush()?;
+    for line in input.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.eq_ignore_ascii_case("q") {
+            break;
+        }
+
+        match parse_choice(line) {
+            Some(mine) => {
+                let other = strategy.choose();
+                let game = Game::new(other, mine);
+                strategy.observe(&Game::new(mine, other));
+                human.record(&game);
+                computer.record(&Game::new(mine, other));
+
+                let verdict = match game.outcome {
+                    Outcome::Win => "You win",
+                    Outcome::Draw => "Draw",
+                    Outcome::Loose => "You lose",
+                };
+                writeln!(
+                    output,
+                    "{mine:?} against {other:?}: {verdict}, +{}. Score: you {}, {} {}.",
+                    game.score(),
+                    human.score,
+                    computer.name,
+                    computer.score
+                )?;
+            }
+            None if line.is_empty() => {}
+            None => writeln!(output, "Unknown choice '{line}'.")?,
+        }
+        write!(output, "{prompt}")?;
+        output.flush()?;
+    }
+
+    writeln!(output)?;
+    writeln!(output, "{human}")?;
+    writeln!(output, "{computer}")?;
+    Ok((human, computer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::Always;
+
+    #[test]
+    fn play_against_rock() {
+        let mut output = vec![];
+        let (human, computer) = play(
+            "p\nrock\nx\n\nS\nq\nr\n".as_bytes(),
+            &mut output,
+            &mut Always(Choice::Rock),
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert_eq!((human.wins, human.draws, human.losses), (1, 1, 1));
+        assert_eq!(human.score, 8 + 4 + 3);
+        assert_eq!(computer.score, 1 + 4 + 7);
+        assert!(output.contains("Paper against Rock: You win, +8. Score: you 8, always-rock 1."));
+        assert!(output.contains("Unknown choice 'x'."));
+    }
+}
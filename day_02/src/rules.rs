@@ -0,0 +1,640 @@
+use crate::{split_line, Column, Error, Outcome};
+use aoc_common::{columns, Pos};
+use itertools::Itertools;
+use serde::Deserialize;
+use std::str::FromStr;
+
+/// One of the moves of a game.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Move {
+    pub name: String,
+    /// Tokens standing for the move in a strategy guide.
+    pub tokens: Vec<String>,
+    /// Score for playing the move.
+    pub score: u32,
+}
+
+impl Move {
+    pub fn new(name: &str, tokens: &[&str], score: u32) -> Self {
+        Self {
+            name: name.to_string(),
+            tokens: tokens.iter().map(|token| token.to_string()).collect(),
+            score,
+        }
+    }
+}
+
+/// How one outcome is written in a strategy guide and scored.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct OutcomeRule {
+    pub token: String,
+    pub score: u32,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Outcomes {
+    pub loss: OutcomeRule,
+    pub draw: OutcomeRule,
+    pub win: OutcomeRule,
+}
+
+impl Outcomes {
+    pub fn get(&self, outcome: Outcome) -> &OutcomeRule {
+        match outcome {
+            Outcome::Loose => &self.loss,
+            Outcome::Draw => &self.draw,
+            Outcome::Win => &self.win,
+        }
+    }
+
+    /// Make sure no token stands for more than one outcome.
+    fn validate(&self) -> Result<(), Error> {
+        let tokens = [&self.loss.token, &self.draw.token, &self.win.token];
+        match tokens.into_iter().duplicates().next() {
+            Some(token) => Err(Error::InvalidRules {
+                reason: format!("'{token}' stands for more than one outcome"),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// The outcome standing for the token.
+    pub fn parse(&self, token: &str) -> Result<Outcome, Error> {
+        [Outcome::Loose, Outcome::Draw, Outcome::Win]
+            .into_iter()
+            .find(|&outcome| self.get(outcome).token == token)
+            .ok_or_else(|| Error::InvalidOutcome {
+                pos: Pos::default(),
+                token: token.to_string(),
+            })
+    }
+}
+
+/// Written and scored as in the puzzle.
+impl Default for Outcomes {
+    fn default() -> Self {
+        let rule = |outcome: Outcome| OutcomeRule {
+            token: outcome.to_string(),
+            score: outcome as u32,
+        };
+        Self {
+            loss: rule(Outcome::Loose),
+            draw: rule(Outcome::Draw),
+            win: rule(Outcome::Win),
+        }
+    }
+}
+
+/// A game like rock paper scissors with any number of moves, each of them
+/// beating some of the others.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rules {
+    pub moves: Vec<Move>,
+    pub outcomes: Outcomes,
+    /// Bit `j` of `beats[i]` is set if move `i` beats move `j`.
+    beats: Vec<u64>,
+}
+
+impl Rules {
+    /// Rules in which the first move of every pair beats the second one.
+    pub fn new(moves: Vec<Move>, beats: &[(usize, usize)]) -> Result<Self, Error> {
+        let invalid = |reason: String| Err(Error::InvalidRules { reason });
+        if moves.len() > 64 {
+            return invalid(format!("{} moves, at most 64 are supported", moves.len()));
+        }
+        let tokens = moves.iter().flat_map(|mv| &mv.tokens);
+        if let Some(token) = tokens.duplicates().next() {
+            return invalid(format!("'{token}' stands for more than one move"));
+        }
+        let names = moves.iter().map(|mv| &mv.name);
+        if let Some(name) = names.duplicates_by(|name| name.to_ascii_lowercase()).next() {
+            return invalid(format!("'{name}' is the name of more than one move"));
+        }
+
+        let mut rules = Self {
+            beats: vec![0; moves.len()],
+            moves,
+            outcomes: Outcomes::default(),
+        };
+        for &(winner, loser) in beats {
+            let name = |idx: usize| rules.moves.get(idx).map(|mv| mv.name.as_str());
+            match (name(winner), name(loser)) {
+                (Some(_), Some(_)) if winner == loser => {
+                    return invalid(format!("'{}' beats itself", rules.moves[winner].name))
+                }
+                (Some(winner_name), Some(loser_name)) if rules.beats(loser, winner) => {
+                    return invalid(format!(
+                        "'{winner_name}' and '{loser_name}' beat each other"
+                    ))
+                }
+                (Some(_), Some(_)) => rules.beats[winner] |= 1 << loser,
+                _ => return invalid(format!("no move {}", winner.max(loser))),
+            }
+        }
+        Ok(rules)
+    }
+
+    /// Balanced rules for an odd number of moves, in which every move beats
+    /// the half of the other moves listed right before it, wrapping around.
+    pub fn cyclic(moves: Vec<Move>) -> Result<Self, Error> {
+        let n = moves.len();
+        if n.is_multiple_of(2) {
+            return Err(Error::InvalidRules {
+                reason: format!("{n} moves, a cyclic game needs an odd number"),
+            });
+        }
+
+        let beats: Vec<(usize, usize)> = (0..n)
+            .flat_map(|winner| (1..=n / 2).map(move |d| (winner, (winner + n - d) % n)))
+            .collect();
+        Self::new(moves, &beats)
+    }
+
+    /// Rock paper scissors, as played in the puzzle.
+    pub fn rock_paper_scissors() -> Self {
+        Self::cyclic(vec![
+            Move::new("Rock", &["A", "X"], 1),
+            Move::new("Paper", &["B", "Y"], 2),
+            Move::new("Scissors", &["C", "Z"], 3),
+        ])
+        .unwrap()
+    }
+
+    /// Rock paper scissors lizard Spock.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        let moves = vec![
+            Move::new("Rock", &["R"], 1),
+            Move::new("Paper", &["P"], 2),
+            Move::new("Scissors", &["S"], 3),
+            Move::new("Lizard", &["L"], 4),
+            Move::new("Spock", &["K"], 5),
+        ];
+        let (rock, paper, scissors, lizard, spock) = (0, 1, 2, 3, 4);
+        let beats = [
+            (scissors, paper),
+            (paper, rock),
+            (rock, lizard),
+            (lizard, spock),
+            (spock, scissors),
+            (scissors, lizard),
+            (lizard, paper),
+            (paper, spock),
+            (spock, rock),
+            (rock, scissors),
+        ];
+        Self::new(moves, &beats).unwrap()
+    }
+
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    pub fn beats(&self, winner: usize, loser: usize) -> bool {
+        self.beats[winner] & (1 << loser) != 0
+    }
+
+    /// Whether every move beats exactly half of the others, and is beaten by
+    /// the other half, so no move is better than another.
+    pub fn is_balanced(&self) -> bool {
+        let n = self.len();
+        !n.is_multiple_of(2)
+            && (0..n).all(|mv| {
+                let wins = (0..n).filter(|&other| self.beats(mv, other)).count();
+                let losses = (0..n).filter(|&other| self.beats(other, mv)).count();
+                wins == n / 2 && losses == n / 2
+            })
+    }
+
+    pub fn outcome(&self, mine: usize, other: usize) -> Outcome {
+        match (self.beats(mine, other), self.beats(other, mine)) {
+            (true, _) => Outcome::Win,
+            (_, true) => Outcome::Loose,
+            _ => Outcome::Draw,
+        }
+    }
+
+    /// The first move ending in the outcome against the other move, if any.
+    pub fn from_outcome(&self, other: usize, outcome: Outcome) -> Option<usize> {
+        (0..self.len()).find(|&mine| self.outcome(mine, other) == outcome)
+    }
+
+    /// Score of one game, the same way as the puzzle scores it.
+    pub fn score(&self, mine: usize, other: usize) -> Result<u32, Error> {
+        let outcome = self.outcomes.get(self.outcome(mine, other)).score;
+        outcome
+            .checked_add(self.moves[mine].score)
+            .ok_or(Error::ScoreOverflow {
+                pos: Pos::default(),
+            })
+    }
+
+    /// The move standing for the token, or called like it.
+    pub fn parse(&self, token: &str) -> Result<usize, Error> {
+        self.moves
+            .iter()
+            .position(|mv| {
+                mv.tokens.iter().any(|t| t == token) || mv.name.eq_ignore_ascii_case(token)
+            })
+            .ok_or_else(|| Error::InvalidChoice {
+                pos: Pos::default(),
+                token: token.to_string(),
+            })
+    }
+
+    /// Score of one line of a strategy guide.
+    fn line_score(&self, line: &str, column: Column) -> Result<u32, Error> {
+        let parse = |token: &str| self.parse(token);
+        let (other, mine) = match column {
+            Column::Choice => split_line(line, parse, parse)?,
+            Column::Outcome => {
+                let (other, outcome) = split_line(line, parse, |t| self.outcomes.parse(t))?;
+                let mine = self.from_outcome(other, outcome).ok_or_else(|| {
+                    let (column, _) = columns(line, " ").nth(1).unwrap();
+                    Error::UnreachableOutcome {
+                        pos: Pos::new(1, column),
+                        token: self.outcomes.get(outcome).token.clone(),
+                    }
+                })?;
+                (other, mine)
+            }
+        };
+        self.score(mine, other)
+    }
+
+    /// Total score of a strategy guide written with the tokens of the moves.
+    pub fn total_score(&self, input: &str, column: Column) -> Result<u32, Error> {
+        let mut total_score: u32 = 0;
+        for (i, line) in input.lines().enumerate() {
+            let pos = Pos::new(i + 1, 1);
+            let score = self
+                .line_score(line, column)
+                .map_err(|err| err.offset(pos))?;
+            total_score = total_score
+                .checked_add(score)
+                .ok_or(Error::ScoreOverflow { pos })?;
+        }
+        Ok(total_score)
+    }
+
+    /// Every way of reading the tokens as standing for one move each, in
+    /// place of any other moves they stand for, with a description of it.
+    /// Every token has to stand for a move already, as otherwise no guide
+    /// written for the rules uses it.
+    pub fn mappings<'a>(
+        &'a self,
+        tokens: &'a [&str],
+    ) -> Result<impl Iterator<Item = (String, Rules)> + 'a, Error> {
+        let unused = tokens.iter().find(|&&token| {
+            !self
+                .moves
+                .iter()
+                .any(|mv| mv.tokens.iter().any(|t| t == token))
+        });
+        if let Some(token) = unused {
+            return Err(Error::InvalidRules {
+                reason: format!("'{token}' stands for no move"),
+            });
+        }
+
+        Ok((0..self.len())
+            .permutations(tokens.len())
+            .map(move |moves| {
+                let mut rules = self.clone();
+                for mv in &mut rules.moves {
+                    mv.tokens.retain(|token| !tokens.contains(&token.as_str()));
+                }
+                for (token, &mv) in tokens.iter().zip(&moves) {
+                    rules.moves[mv].tokens.push(token.to_string());
+                }
+
+                let description = tokens
+                    .iter()
+                    .zip(&moves)
+                    .map(|(token, &mv)| format!("{token}={}", self.moves[mv].name))
+                    .join(" ");
+                (description, rules)
+            }))
+    }
+}
+
+/// Games whose rules are known without a rules file.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Preset {
+    /// Rock paper scissors, as played in the puzzle.
+    #[default]
+    RockPaperScissors,
+    /// Rock paper scissors lizard Spock, written as R, P, S, L and K.
+    RockPaperScissorsLizardSpock,
+}
+
+impl Preset {
+    pub fn rules(&self) -> Rules {
+        match self {
+            Self::RockPaperScissors => Rules::rock_paper_scissors(),
+            Self::RockPaperScissorsLizardSpock => Rules::rock_paper_scissors_lizard_spock(),
+        }
+    }
+}
+
+impl FromStr for Preset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rps" => Ok(Self::RockPaperScissors),
+            "rpsls" => Ok(Self::RockPaperScissorsLizardSpock),
+            _ => Err(format!("Invalid game: {s}")),
+        }
+    }
+}
+
+/// A move as written in the rules file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MoveConfig {
+    name: String,
+    #[serde(default)]
+    tokens: Vec<String>,
+    score: u32,
+    /// Names of the moves this one beats.
+    #[serde(default)]
+    beats: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    moves: Vec<MoveConfig>,
+    #[serde(default)]
+    outcomes: Outcomes,
+}
+
+/// Parses the rules from TOML, as in `day_02/rules.toml`.
+impl FromStr for Rules {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let config: Config = toml::from_str(s).map_err(|err| Error::InvalidRules {
+            reason: err.message().to_string(),
+        })?;
+
+        let idx = |name: &str| {
+            config
+                .moves
+                .iter()
+                .position(|mv| mv.name == name)
+                .ok_or_else(|| Error::InvalidRules {
+                    reason: format!("no move '{name}'"),
+                })
+        };
+        let mut beats = vec![];
+        for (winner, mv) in config.moves.iter().enumerate() {
+            for loser in &mv.beats {
+                beats.push((winner, idx(loser)?));
+            }
+        }
+
+        let moves = config
+            .moves
+            .iter()
+            .map(|mv| Move {
+                name: mv.name.clone(),
+                tokens: mv.tokens.clone(),
+                score: mv.score,
+            })
+            .collect();
+        let mut rules = Self::new(moves, &beats)?;
+        config.outcomes.validate()?;
+        rules.outcomes = config.outcomes;
+        Ok(rules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Choice, Outcome};
+
+    #[test]
+    fn rock_paper_scissors_preset() {
+        let rules = Rules::rock_paper_scissors();
+        let example = include_str!("../example.txt");
+
+        assert_eq!(rules.total_score(example, Column::Choice), Ok(15));
+        assert_eq!(rules.total_score(example, Column::Outcome), Ok(12));
+        for mine in Choice::ALL {
+            for other in Choice::ALL {
+                assert_eq!(
+                    rules.outcome(mine.index(), other.index()),
+                    Outcome::from_choices(mine, other)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn balanced() {
+        assert!(Rules::rock_paper_scissors().is_balanced());
+        assert!(Rules::rock_paper_scissors_lizard_spock().is_balanced());
+
+        let moves = (1..=7).map(|i| Move::new(&i.to_string(), &[], i)).collect();
+        assert!(Rules::cyclic(moves).unwrap().is_balanced());
+    }
+
+    #[test]
+    fn lizard_spock() {
+        let rules = Rules::rock_paper_scissors_lizard_spock();
+        let [rock, paper, scissors, lizard, spock] = [0, 1, 2, 3, 4];
+
+        assert_eq!(rules.outcome(spock, scissors), Outcome::Win);
+        assert_eq!(rules.outcome(lizard, rock), Outcome::Loose);
+        assert_eq!(rules.outcome(paper, paper), Outcome::Draw);
+        assert_eq!(rules.total_score("S K\nL R\n", Column::Choice), Ok(18));
+    }
+
+    #[test]
+    fn from_toml() {
+        let rules: Rules = include_str!("../rules.toml").parse().unwrap();
+        assert_eq!(rules, Rules::rock_paper_scissors());
+
+        let rules: Rules = r#"
+            moves = [
+                { name = "Rock", tokens = ["A", "X"], score = 0, beats = ["Scissors"] },
+                { name = "Paper", tokens = ["B", "Y"], score = 0, beats = ["Rock"] },
+                { name = "Scissors", tokens = ["C", "Z"], score = 0, beats = ["Paper"] },
+            ]
+
+            [outcomes]
+            loss = { token = "L", score = 0 }
+            draw = { token = "D", score = 1 }
+            win = { token = "W", score = 2 }
+        "#
+        .parse()
+        .unwrap();
+        assert_eq!(rules.total_score("A Y\nB X\nC Z\n", Column::Choice), Ok(3));
+        assert_eq!(rules.total_score("A W\nB D\n", Column::Outcome), Ok(3));
+    }
+
+    #[test]
+    fn presets() {
+        let rules: Rules = include_str!("../rpsls.toml").parse().unwrap();
+        assert_eq!(rules, "rpsls".parse::<Preset>().unwrap().rules());
+        assert_eq!(Preset::default().rules(), Rules::rock_paper_scissors());
+        assert!("rpsl".parse::<Preset>().is_err());
+    }
+
+    #[test]
+    fn score_overflow() {
+        let rules: Rules = r#"
+            moves = [
+                { name = "Rock", tokens = ["A", "X"], score = 4000000000, beats = ["Paper"] },
+                { name = "Paper", tokens = ["B", "Y"], score = 2 },
+            ]
+
+            [outcomes]
+            loss = { token = "L", score = 0 }
+            draw = { token = "D", score = 3 }
+            win = { token = "W", score = 6 }
+        "#
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            rules.total_score("A X\nB Y\nA X\n", Column::Choice),
+            Err(Error::ScoreOverflow {
+                pos: Pos::new(3, 1)
+            })
+        );
+        assert_eq!(
+            rules.total_score("A D\nA D\n", Column::Outcome),
+            Err(Error::ScoreOverflow {
+                pos: Pos::new(2, 1)
+            })
+        );
+
+        let mut rules = rules;
+        rules.outcomes.draw.score = u32::MAX;
+        assert!(rules.score(1, 1).is_err());
+        assert_eq!(
+            rules.total_score("B Y\n", Column::Choice),
+            Err(Error::ScoreOverflow {
+                pos: Pos::new(1, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn unreachable_outcome() {
+        let rules: Rules = r#"
+            moves = [
+                { name = "Rock", tokens = ["A"], score = 1, beats = ["Paper"] },
+                { name = "Paper", tokens = ["B"], score = 2 },
+            ]
+
+            [outcomes]
+            loss = { token = "L", score = 0 }
+            draw = { token = "D", score = 3 }
+            win = { token = "W", score = 6 }
+        "#
+        .parse()
+        .unwrap();
+
+        assert_eq!(rules.total_score("B W\n", Column::Outcome), Ok(7));
+        assert_eq!(
+            rules.total_score("B W\nA W\n", Column::Outcome),
+            Err(Error::UnreachableOutcome {
+                pos: Pos::new(2, 3),
+                token: "W".to_string(),
+            })
+        );
+        assert_eq!(
+            rules.total_score("A Z\n", Column::Outcome),
+            Err(Error::InvalidOutcome {
+                pos: Pos::new(1, 3),
+                token: "Z".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn mappings() {
+        let rules = Rules::rock_paper_scissors();
+        let example = include_str!("../example.txt");
+        let scores: Vec<(String, u32)> = rules
+            .mappings(&["X", "Y", "Z"])
+            .unwrap()
+            .map(|(mapping, rules)| (mapping, rules.total_score(example, Column::Choice).unwrap()))
+            .collect();
+
+        assert_eq!(scores.len(), 6);
+        assert_eq!(scores[0], ("X=Rock Y=Paper Z=Scissors".to_string(), 15));
+        assert!(scores.contains(&("X=Scissors Y=Rock Z=Paper".to_string(), 4 + 9 + 2)));
+        assert_eq!(
+            Rules::rock_paper_scissors_lizard_spock()
+                .mappings(&["X", "Y", "Z"])
+                .err(),
+            Some(Error::InvalidRules {
+                reason: "'X' stands for no move".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_rules() {
+        let moves = || vec![Move::new("a", &[], 1), Move::new("b", &[], 2)];
+
+        assert!(Rules::new(moves(), &[(0, 0)]).is_err());
+        assert!(Rules::new(moves(), &[(0, 1), (1, 0)]).is_err());
+        assert!(Rules::new(moves(), &[(0, 2)]).is_err());
+        assert!(Rules::cyclic(moves()).is_err());
+        assert!("moves = [{ name = \"a\", score = 1, beats = [\"b\"] }]"
+            .parse::<Rules>()
+            .is_err());
+        assert!(Rules::new(
+            vec![Move::new("a", &["A"], 1), Move::new("b", &["A"], 2)],
+            &[]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn duplicates_in_file() {
+        let duplicate_move = r#"
+            moves = [
+                { name = "Rock", tokens = ["A"], score = 1, beats = ["Paper"] },
+                { name = "Paper", tokens = ["B"], score = 2 },
+                { name = "Rock", tokens = ["C"], score = 3 },
+            ]
+        "#;
+        assert_eq!(
+            duplicate_move.parse::<Rules>(),
+            Err(Error::InvalidRules {
+                reason: "'Rock' is the name of more than one move".to_string()
+            })
+        );
+
+        let duplicate_outcome = r#"
+            moves = [
+                { name = "Rock", tokens = ["A"], score = 1, beats = ["Paper"] },
+                { name = "Paper", tokens = ["B"], score = 2 },
+            ]
+
+            [outcomes]
+            loss = { token = "X", score = 0 }
+            draw = { token = "X", score = 3 }
+            win = { token = "Z", score = 6 }
+        "#;
+        assert_eq!(
+            duplicate_outcome.parse::<Rules>(),
+            Err(Error::InvalidRules {
+                reason: "'X' stands for more than one outcome".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,277 @@
+use crate::{Choice, Error, Game, Outcome};
+use aoc_common::{columns, Pos};
+use std::str::FromStr;
+
+/// Whether to score as high or as low as possible.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Goal {
+    Max,
+    Min,
+}
+
+impl FromStr for Goal {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "max" => Ok(Self::Max),
+            "min" => Ok(Self::Min),
+            _ => Err(format!("Invalid goal: {s}")),
+        }
+    }
+}
+
+impl Goal {
+    fn better(self, score: u32, than: u32) -> bool {
+        match self {
+            Self::Max => score > than,
+            Self::Min => score < than,
+        }
+    }
+}
+
+/// The opponent's column of a strategy guide. The second column is optional,
+/// and ignored.
+pub fn parse_opponent(input: &str) -> Result<Vec<Choice>, Error> {
+    let mut other = vec![];
+    for (i, line) in input.lines().enumerate() {
+        let mut parts = columns(line, " ");
+        let (column, token) = parts.next().ok_or(Error::MissingToken {
+            pos: Pos::new(i + 1, line.len() + 1),
+        })?;
+        let choice = token
+            .parse()
+            .map_err(|err: Error| err.offset(Pos::new(i + 1, column)))?;
+        if let Some((column, token)) = parts.nth(1) {
+            return Err(Error::UnexpectedToken {
+                pos: Pos::new(i + 1, column),
+                token: token.to_string(),
+            });
+        }
+        other.push(choice);
+    }
+    Ok(other)
+}
+
+/// Games played with my choices against the opponent's.
+pub fn games(other: &[Choice], mine: &[Choice]) -> Vec<Game> {
+    other
+        .iter()
+        .zip(mine)
+        .map(|(&other, &mine)| Game::new(other, mine))
+        .collect()
+}
+
+/// My choices against the opponent's, scoring as high or as low as possible
+/// while ending in `outcome` at most `limit` times.
+///
+/// For every game and every number of times `outcome` came up so far, only the
+/// best score is kept, which takes `O(games * limit)` steps. Of equally good
+/// choices, the one first in [`Choice::ALL`] is taken.
+pub fn optimize(other: &[Choice], goal: Goal, outcome: Outcome, limit: usize) -> Vec<Choice> {
+    let limit = limit.min(other.len());
+    let counts = |game: &Game| (game.outcome == outcome) as usize;
+
+    let mut best: Vec<Option<u32>> = vec![None; limit + 1];
+    best[0] = Some(0);
+    // choice taken in every game to reach every count
+    let mut taken: Vec<Vec<Option<Choice>>> = Vec::with_capacity(other.len());
+    for &other in other {
+        let mut next = vec![None; limit + 1];
+        let mut choices = vec![None; limit + 1];
+        for (count, score) in best.iter().enumerate() {
+            let Some(score) = score else { continue };
+            for mine in Choice::ALL {
+                let game = Game::new(other, mine);
+                let (count, score) = (count + counts(&game), score + game.score());
+                if count <= limit && next[count].is_none_or(|best| goal.better(score, best)) {
+                    next[count] = Some(score);
+                    choices[count] = Some(mine);
+                }
+            }
+        }
+        best = next;
+        taken.push(choices);
+    }
+
+    let (mut count, _) = best
+        .iter()
+        .enumerate()
+        .filter_map(|(count, score)| Some((count, (*score)?)))
+        .reduce(|a, b| if goal.better(b.1, a.1) { b } else { a })
+        .unwrap();
+    let mut mine = vec![];
+    for (choices, &other) in taken.iter().zip(other).rev() {
+        let choice = choices[count].unwrap();
+        count -= counts(&Game::new(other, choice));
+        mine.push(choice);
+    }
+    mine.reverse();
+    mine
+}
+
+/// Sets of totals, one bit per total.
+struct Totals(Vec<u64>);
+
+impl Totals {
+    fn contains(&self, total: usize) -> bool {
+        self.0
+            .get(total / 64)
+            .is_some_and(|word| word >> (total % 64) & 1 == 1)
+    }
+
+    /// Add every total of `other`, increased by `by`.
+    fn add_shifted(&mut self, other: &Totals, by: usize) {
+        let (words, bits) = (by / 64, by % 64);
+        for (i, &word) in other.0.iter().enumerate() {
+            if let Some(dst) = self.0.get_mut(i + words) {
+                *dst |= word << bits;
+            }
+            if bits > 0 {
+                if let Some(dst) = self.0.get_mut(i + words + 1) {
+                    *dst |= word >> (64 - bits);
+                }
+            }
+        }
+    }
+}
+
+/// My choices against the opponent's, scoring exactly `target`, if any do.
+///
+/// The totals reachable after every game are kept as bit sets, which takes
+/// `O(games * target)` steps. Targets above the highest possible score are
+/// rejected right away.
+pub fn hit_target(other: &[Choice], target: u32) -> Option<Vec<Choice>> {
+    let target = target as usize;
+    let highest: usize = other
+        .iter()
+        .map(|&other| {
+            Choice::ALL
+                .into_iter()
+                .map(|mine| Game::new(other, mine).score() as usize)
+                .max()
+                .unwrap()
+        })
+        .sum();
+    if target > highest {
+        return None;
+    }
+    let words = target / 64 + 1;
+
+    let mut reachable = vec![Totals(vec![0; words])];
+    reachable[0].0[0] = 1;
+    for &other in other {
+        let mut next = Totals(vec![0; words]);
+        for mine in Choice::ALL {
+            next.add_shifted(
+                reachable.last().unwrap(),
+                Game::new(other, mine).score() as usize,
+            );
+        }
+        reachable.push(next);
+    }
+    if !reachable.last()?.contains(target) {
+        return None;
+    }
+
+    let mut total = target;
+    let mut mine = vec![];
+    for (before, &other) in reachable.iter().zip(other).rev() {
+        let choice = Choice::ALL.into_iter().find(|&mine| {
+            let score = Game::new(other, mine).score() as usize;
+            score <= total && before.contains(total - score)
+        })?;
+        total -= Game::new(other, choice).score() as usize;
+        mine.push(choice);
+    }
+    mine.reverse();
+    Some(mine)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_guide, Column};
+
+    fn other() -> Vec<Choice> {
+        parse_opponent(include_str!("../example.txt")).unwrap()
+    }
+
+    fn total(other: &[Choice], mine: &[Choice]) -> u32 {
+        games(other, mine).iter().map(Game::score).sum()
+    }
+
+    #[test]
+    fn optimize_without_limit() {
+        let other = other();
+        let max = optimize(&other, Goal::Max, Outcome::Win, other.len());
+        let min = optimize(&other, Goal::Min, Outcome::Win, other.len());
+
+        assert_eq!(total(&other, &max), 8 + 9 + 7);
+        assert_eq!(total(&other, &min), 3 + 1 + 2);
+    }
+
+    #[test]
+    fn optimize_with_limit() {
+        let other = other();
+        let mine = optimize(&other, Goal::Max, Outcome::Win, 1);
+        let wins = games(&other, &mine)
+            .iter()
+            .filter(|game| game.outcome == Outcome::Win)
+            .count();
+
+        assert_eq!(wins, 1);
+        assert_eq!(total(&other, &mine), 8 + 5 + 6);
+    }
+
+    #[test]
+    fn hit_every_target() {
+        let other = other();
+        let mut reachable = vec![];
+        for a in Choice::ALL {
+            for b in Choice::ALL {
+                for c in Choice::ALL {
+                    reachable.push(total(&other, &[a, b, c]));
+                }
+            }
+        }
+
+        for target in 0..100 {
+            match hit_target(&other, target) {
+                Some(mine) => assert_eq!(total(&other, &mine), target),
+                None => assert!(!reachable.contains(&target)),
+            }
+        }
+    }
+
+    #[test]
+    fn unreachable_target() {
+        let other = other();
+
+        assert!(hit_target(&other, 9 * other.len() as u32 + 1).is_none());
+        assert!(hit_target(&other, u32::MAX).is_none());
+    }
+
+    #[test]
+    fn opponent_column() {
+        let guide = parse_guide(include_str!("../example.txt"), Column::Choice).unwrap();
+        let expected: Vec<Choice> = guide.iter().map(|game| game.other).collect();
+
+        assert_eq!(other(), expected);
+        assert_eq!(parse_opponent("A\nB\nC\n"), Ok(Choice::ALL.to_vec()));
+        assert_eq!(
+            parse_opponent("A\nB Y Z\n"),
+            Err(Error::UnexpectedToken {
+                pos: Pos::new(2, 5),
+                token: "Z".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_opponent("A X\nD\n"),
+            Err(Error::InvalidChoice {
+                pos: Pos::new(2, 1),
+                token: "D".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use crate::{Choice, Game, Outcome};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fmt;
+
+/// A way of choosing what to play, game after game against the same opponent.
+pub trait Strategy {
+    fn name(&self) -> &str;
+
+    /// What to play in the next game.
+    fn choose(&mut self) -> Choice;
+
+    /// Learn from the game just played, seen from this strategy's side.
+    fn observe(&mut self, _game: &Game) {}
+
+    /// Forget everything learned, before playing against a new opponent.
+    fn reset(&mut self) {}
+}
+
+/// The choice beating `choice`.
+fn beating(choice: Choice) -> Choice {
+    Choice::from_outcome(choice, Outcome::Win)
+}
+
+/// Plays the choices of one column of the strategy guide, over and over.
+pub struct Guide {
+    name: String,
+    choices: Vec<Choice>,
+    next: usize,
+}
+
+impl Guide {
+    pub fn new(name: &str, choices: Vec<Choice>) -> Self {
+        Self {
+            name: name.to_string(),
+            choices,
+            next: 0,
+        }
+    }
+}
+
+impl Strategy for Guide {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn choose(&mut self) -> Choice {
+        let choice = self.choices[self.next % self.choices.len()];
+        self.next += 1;
+        choice
+    }
+
+    fn reset(&mut self) {
+        self.next = 0;
+    }
+}
+
+/// Plays any choice, with equal chances.
+pub struct Random {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Strategy for Random {
+    fn name(&self) -> &str {
+        "random"
+    }
+
+    fn choose(&mut self) -> Choice {
+        Choice::ALL[self.rng.random_range(0..Choice::ALL.len())]
+    }
+
+    fn reset(&mut self) {
+        self.rng = StdRng::seed_from_u64(self.seed);
+    }
+}
+
+/// Plays the same choice every time.
+pub struct Always(pub Choice);
+
+impl Strategy for Always {
+    fn name(&self) -> &str {
+        match self.0 {
+            Choice::Rock => "always-rock",
+            Choice::Paper => "always-paper",
+            Choice::Scissor => "always-scissor",
+        }
+    }
+
+    fn choose(&mut self) -> Choice {
+        self.0
+    }
+}
+
+/// Beats the choice the opponent played most often so far.
+#[derive(Default)]
+pub struct FrequencyCounter {
+    counts: [u32; 3],
+}
+
+impl Strategy for FrequencyCounter {
+    fn name(&self) -> &str {
+        "frequency"
+    }
+
+    fn choose(&mut self) -> Choice {
+        let (most, _) = Choice::ALL
+            .iter()
+            .zip(self.counts)
+            .rev()
+            .max_by_key(|&(_, count)| count)
+            .unwrap();
+        beating(*most)
+    }
+
+    fn observe(&mut self, game: &Game) {
+        self.counts[game.other.index()] += 1;
+    }
+
+    fn reset(&mut self) {
+        self.counts = [0; 3];
+    }
+}
+
+/// Beats the choice the opponent played most often after its last choice, as
+/// a first-order Markov chain predicts it.
+#[derive(Default)]
+pub struct Markov {
+    transitions: [[u32; 3]; 3],
+    last: Option<Choice>,
+}
+
+impl Strategy for Markov {
+    fn name(&self) -> &str {
+        "markov"
+    }
+
+    fn choose(&mut self) -> Choice {
+        let counts = match self.last {
+            Some(last) => self.transitions[last.index()],
+            None => [0; 3],
+        };
+        let (predicted, _) = Choice::ALL
+            .iter()
+            .zip(counts)
+            .rev()
+            .max_by_key(|&(_, count)| count)
+            .unwrap();
+        beating(*predicted)
+    }
+
+    fn observe(&mut self, game: &Game) {
+        if let Some(last) = self.last {
+            self.transitions[last.index()][game.other.index()] += 1;
+        }
+        self.last = Some(game.other);
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// The strategy called `name`, other than the guides.
+pub fn named(name: &str, seed: u64) -> Result<Box<dyn Strategy>, String> {
+    match name {
+        "random" => Ok(Box::new(Random::new(seed))),
+        "always-rock" => Ok(Box::new(Always(Choice::Rock))),
+        "always-paper" => Ok(Box::new(Always(Choice::Paper))),
+        "always-scissor" => Ok(Box::new(Always(Choice::Scissor))),
+        "frequency" => Ok(Box::new(FrequencyCounter::default())),
+        "markov" => Ok(Box::new(Markov::default())),
+        _ => Err(format!("Unknown strategy: {name}")),
+    }
+}
+
+/// Result of one strategy in a match or tournament.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Standing {
+    pub name: String,
+    pub score: u64,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+}
+
+impl Standing {
+    pub fn record(&mut self, game: &Game) {
+        self.score += game.score() as u64;
+        match game.outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Loose => self.losses += 1,
+        }
+    }
+}
+
+impl fmt::Display for Standing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<16} {:>8}  {}W {}D {}L",
+            self.name, self.score, self.wins, self.draws, self.losses
+        )
+    }
+}
+
+/// Let two strategies play `rounds` games against each other, after both
+/// forgot what they learned before.
+pub fn play(
+    first: &mut dyn Strategy,
+    second: &mut dyn Strategy,
+    rounds: usize,
+) -> (Standing, Standing) {
+    first.reset();
+    second.reset();
+    let mut standings = (
+        Standing {
+            name: first.name().to_string(),
+            ..Default::default()
+        },
+        Standing {
+            name: second.name().to_string(),
+            ..Default::default()
+        },
+    );
+
+    for _ in 0..rounds {
+        let (a, b) = (first.choose(), second.choose());
+        let games = (Game::new(b, a), Game::new(a, b));
+
+        first.observe(&games.0);
+        second.observe(&games.1);
+        standings.0.record(&games.0);
+        standings.1.record(&games.1);
+    }
+    standings
+}
+
+/// Let every strategy play `rounds` games against every other one, adding up
+/// the results. The standings are ordered by score, the highest first.
+pub fn tournament(strategies: &mut [Box<dyn Strategy>], rounds: usize) -> Vec<Standing> {
+    let mut standings: Vec<Standing> = strategies
+        .iter()
+        .map(|strategy| Standing {
+            name: strategy.name().to_string(),
+            ..Default::default()
+        })
+        .collect();
+
+    for i in 0..strategies.len() {
+        for j in i + 1..strategies.len() {
+            let (left, right) = strategies.split_at_mut(j);
+            let (first, second) = play(left[i].as_mut(), right[0].as_mut(), rounds);
+            for (standing, result) in [(i, first), (j, second)] {
+                let standing = &mut standings[standing];
+                standing.score += result.score;
+                standing.wins += result.wins;
+                standing.draws += result.draws;
+                standing.losses += result.losses;
+            }
+        }
+    }
+
+    standings.sort_by_key(|standing| std::cmp::Reverse(standing.score));
+    standings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guide_scores_like_part1() {
+        let games =
+            crate::parse_guide(include_str!("../example.txt"), crate::Column::Choice).unwrap();
+        let mut mine = Guide::new("mine", games.iter().map(|game| game.mine).collect());
+        let mut other = Guide::new("other", games.iter().map(|game| game.other).collect());
+
+        let (mine, _) = play(&mut mine, &mut other, games.len());
+        assert_eq!(mine.score, 15);
+    }
+
+    #[test]
+    fn frequency_beats_always() {
+        let (frequency, rock) = play(
+            &mut FrequencyCounter::default(),
+            &mut Always(Choice::Rock),
+            100,
+        );
+
+        assert_eq!(frequency.wins, 100);
+        assert_eq!(rock.losses, 100);
+    }
+
+    #[test]
+    fn markov_beats_cycle() {
+        let cycle = vec![Choice::Rock, Choice::Paper, Choice::Scissor];
+        let (markov, _) = play(&mut Markov::default(), &mut Guide::new("cycle", cycle), 300);
+
+        assert!(markov.wins >= 295);
+    }
+
+    #[test]
+    fn tournament_adds_up() {
+        let mut strategies: Vec<Box<dyn Strategy>> = vec![
+            Box::new(Always(Choice::Rock)),
+            Box::new(Always(Choice::Paper)),
+            Box::new(Random::new(1)),
+        ];
+        let standings = tournament(&mut strategies, 10);
+
+        assert_eq!(standings.len(), 3);
+        for standing in &standings {
+            assert_eq!(standing.wins + standing.draws + standing.losses, 20);
+        }
+        let paper = standings.iter().find(|s| s.name == "always-paper").unwrap();
+        assert!(paper.wins >= 10);
+    }
+}
@@ -0,0 +1,92 @@
+//! Compares finding the common items with `ItemSet` against the `HashSet`
+//! intersection it replaced, on the puzzle input.
+//!
+//! Run with `cargo bench -p day_03`.
+
+use day_03::ItemSet;
+use std::collections::HashSet;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const INPUT: &str = include_str!("../input.txt");
+const RUNS: u32 = 200;
+
+fn priority(c: char) -> usize {
+    ItemSet::priority_of(c).unwrap()
+}
+
+fn hash_set(s: &str) -> HashSet<char> {
+    s.chars().collect()
+}
+
+fn hash_set_part1(input: &str) -> usize {
+    input
+        .lines()
+        .map(|line| {
+            let (first, second) = line.split_at(line.len() / 2);
+            priority(
+                *hash_set(first)
+                    .intersection(&hash_set(second))
+                    .next()
+                    .unwrap(),
+            )
+        })
+        .sum()
+}
+
+fn hash_set_part2(input: &str) -> usize {
+    let lines: Vec<&str> = input.lines().collect();
+    lines
+        .chunks(3)
+        .map(|group| {
+            let common: HashSet<char> = hash_set(group[0])
+                .intersection(&hash_set(group[1]))
+                .copied()
+                .collect();
+            priority(*common.intersection(&hash_set(group[2])).next().unwrap())
+        })
+        .sum()
+}
+
+fn item_set_part1(input: &str) -> usize {
+    input
+        .lines()
+        .map(|line| day_03::find_wrong_item(line).unwrap())
+        .sum()
+}
+
+fn item_set_part2(input: &str) -> usize {
+    let lines: Vec<&str> = input.lines().collect();
+    lines
+        .chunks(3)
+        .map(|group| day_03::find_group(group).unwrap())
+        .sum()
+}
+
+/// Median time of a run.
+fn time(f: impl Fn(&str) -> usize) -> Duration {
+    let mut times: Vec<Duration> = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f(black_box(INPUT)));
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    times[times.len() / 2]
+}
+
+fn compare(name: &str, hash_set: fn(&str) -> usize, item_set: fn(&str) -> usize) {
+    assert_eq!(hash_set(INPUT), item_set(INPUT));
+    let before = time(hash_set);
+    let after = time(item_set);
+    println!(
+        "{name}: HashSet {before:>10.2?}, ItemSet {after:>10.2?}, {:.1}x faster",
+        before.as_secs_f64() / after.as_secs_f64()
+    );
+}
+
+fn main() {
+    compare("part 1", hash_set_part1, item_set_part1);
+    compare("part 2", hash_set_part2, item_set_part2);
+}
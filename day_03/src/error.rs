@@ -0,0 +1,49 @@
+use aoc_common::Pos;
+use std::fmt;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    InvalidItem { pos: Pos, token: char },
+    NoCommonItem { pos: Pos, token: String },
+    OddItems { pos: Pos, token: String },
+    IncompleteGroup { pos: Pos },
+}
+
+impl Error {
+    /// Move the error from the position in a single line to the position in
+    /// the whole input.
+    pub fn offset(self, origin: Pos) -> Self {
+        match self {
+            Self::InvalidItem { pos, token } => Self::InvalidItem {
+                pos: pos.offset(origin),
+                token,
+            },
+            Self::NoCommonItem { pos, token } => Self::NoCommonItem {
+                pos: pos.offset(origin),
+                token,
+            },
+            Self::OddItems { pos, token } => Self::OddItems {
+                pos: pos.offset(origin),
+                token,
+            },
+            Self::IncompleteGroup { pos } => Self::IncompleteGroup {
+                pos: pos.offset(origin),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidItem { pos, token } => write!(f, "{pos}: invalid item '{token}'"),
+            Self::NoCommonItem { pos, token } => write!(f, "{pos}: no common item in '{token}'"),
+            Self::OddItems { pos, token } => {
+                write!(f, "{pos}: odd number of items in '{token}'")
+            }
+            Self::IncompleteGroup { pos } => write!(f, "{pos}: group of less than three elves"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
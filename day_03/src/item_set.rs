@@ -0,0 +1,152 @@
+use crate::Error;
+use aoc_common::Pos;
+use std::fmt;
+use std::ops::{BitAnd, BitOr};
+use std::str::FromStr;
+
+/// Set of items, one bit per priority, so sets can be combined without
+/// allocating.
+#[derive(Copy, Clone, Default, Eq, PartialEq)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    /// Priority of an item: `a` to `z` are 1 to 26, `A` to `Z` 27 to 52.
+    pub fn priority_of(item: char) -> Option<usize> {
+        match item {
+            'a'..='z' => Some(item as usize - 'a' as usize + 1),
+            'A'..='Z' => Some(item as usize - 'A' as usize + 27),
+            _ => None,
+        }
+    }
+
+    fn item(priority: usize) -> char {
+        match priority {
+            1..=26 => (b'a' + priority as u8 - 1) as char,
+            _ => (b'A' + priority as u8 - 27) as char,
+        }
+    }
+
+    /// Set of every item with a priority.
+    pub fn all() -> Self {
+        Self(((1 << 52) - 1) << 1)
+    }
+
+    pub fn insert(&mut self, item: char) -> bool {
+        match Self::priority_of(item) {
+            Some(priority) => {
+                self.0 |= 1 << priority;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        Self::priority_of(item).is_some_and(|priority| self.0 & 1 << priority != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Lowest priority of the items, if there are any.
+    pub fn priority(&self) -> Option<usize> {
+        match self.0 {
+            0 => None,
+            bits => Some(bits.trailing_zeros() as usize),
+        }
+    }
+
+    /// The items, by priority.
+    pub fn iter(&self) -> impl Iterator<Item = char> {
+        let bits = self.0;
+        (1..=52)
+            .filter(move |priority| bits & 1 << priority != 0)
+            .map(Self::item)
+    }
+}
+
+/// Parses the items of a rucksack, every one of which must have a priority.
+impl FromStr for ItemSet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = Self::default();
+        for (i, item) in s.chars().enumerate() {
+            if !set.insert(item) {
+                return Err(Error::InvalidItem {
+                    pos: Pos::new(1, i + 1),
+                    token: item,
+                });
+            }
+        }
+        Ok(set)
+    }
+}
+
+/// Items in both sets.
+impl BitAnd for ItemSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+/// Items in either set.
+impl BitOr for ItemSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl fmt::Debug for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Writes the items by priority.
+impl fmt::Display for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.iter().try_for_each(|item| write!(f, "{item}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn priorities() {
+        let all: ItemSet = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"
+            .parse()
+            .unwrap();
+
+        assert_eq!(all, ItemSet::all());
+        assert_eq!(all.len(), 52);
+        for (i, item) in all.iter().enumerate() {
+            assert_eq!(ItemSet::priority_of(item), Some(i + 1));
+        }
+        assert_eq!("p".parse::<ItemSet>().unwrap().priority(), Some(16));
+        assert_eq!("L".parse::<ItemSet>().unwrap().priority(), Some(38));
+        assert_eq!(ItemSet::default().priority(), None);
+    }
+
+    #[test]
+    fn operators() {
+        let a: ItemSet = "vJrwpWtwJgWr".parse().unwrap();
+        let b: ItemSet = "hcsFMMfFFhFp".parse().unwrap();
+
+        assert_eq!((a & b).to_string(), "p");
+        assert_eq!((a | b).len(), a.len() + b.len() - 1);
+        assert!((a | b).contains('F') && !(a & b).contains('F'));
+        assert_eq!("cbac".parse::<ItemSet>().unwrap().to_string(), "abc");
+    }
+}
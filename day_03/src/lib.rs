@@ -0,0 +1,183 @@
+mod error;
+mod item_set;
+
+pub use error::Error;
+pub use item_set::ItemSet;
+
+use aoc_common::{phase, Answer, Pos, Solution};
+
+/// Items in the two compartments of a rucksack.
+pub fn compartments(line: &str) -> Result<(ItemSet, ItemSet), Error> {
+    let (container1, container2) = match line.split_at_checked(line.len() / 2) {
+        Some(containers) => containers,
+        // Only items without a priority take up more than one byte.
+        None => return Err(line.parse::<ItemSet>().unwrap_err()),
+    };
+    let first: ItemSet = container1.parse()?;
+    let second: ItemSet = container2
+        .parse()
+        .map_err(|err: Error| err.offset(Pos::new(1, container1.len() + 1)))?;
+    if container1.len() != container2.len() {
+        return Err(Error::OddItems {
+            pos: Pos::default(),
+            token: line.to_string(),
+        });
+    }
+    Ok((first, second))
+}
+
+fn wrong_item(line: &str, first: ItemSet, second: ItemSet) -> Result<usize, Error> {
+    (first & second)
+        .priority()
+        .ok_or_else(|| Error::NoCommonItem {
+            pos: Pos::default(),
+            token: line.to_string(),
+        })
+}
+
+pub fn find_wrong_item(line: &str) -> Result<usize, Error> {
+    let (first, second) = compartments(line)?;
+    wrong_item(line, first, second)
+}
+
+/// Items in every rucksack, one per line.
+pub fn parse(lines: &[&str]) -> Result<Vec<ItemSet>, Error> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse()
+                .map_err(|err: Error| err.offset(Pos::new(i + 1, 1)))
+        })
+        .collect()
+}
+
+fn badge(group: &[&str], rucksacks: &[ItemSet]) -> Result<usize, Error> {
+    if group.len() < 3 {
+        return Err(Error::IncompleteGroup {
+            pos: Pos::default(),
+        });
+    }
+
+    let common = rucksacks
+        .iter()
+        .fold(ItemSet::all(), |common, &items| common & items);
+    common.priority().ok_or_else(|| Error::NoCommonItem {
+        pos: Pos::default(),
+        token: group.join(", "),
+    })
+}
+
+pub fn find_group(group: &[&str]) -> Result<usize, Error> {
+    badge(group, &parse(group)?)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let lines: Vec<&str> = input.lines().collect();
+        let rucksacks = phase("parse", || {
+            lines
+                .iter()
+                .enumerate()
+                .map(|(i, line)| compartments(line).map_err(|err| err.offset(Pos::new(i + 1, 1))))
+                .collect::<Result<Vec<_>, Error>>()
+        })?;
+        let total_score = phase("solve", || -> Result<usize, Error> {
+            let mut total_score = 0;
+            for (i, (line, &(first, second))) in lines.iter().zip(&rucksacks).enumerate() {
+                total_score += wrong_item(line, first, second)
+                    .map_err(|err| err.offset(Pos::new(i + 1, 1)))?;
+            }
+            Ok(total_score)
+        })?;
+        Ok(total_score.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let lines: Vec<&str> = input.lines().collect();
+        let rucksacks = phase("parse", || parse(&lines))?;
+        let total_score = phase("solve", || -> Result<usize, Error> {
+            let mut total_score = 0;
+            let groups = lines.chunks(3).zip(rucksacks.chunks(3));
+            for (i, (group, rucksacks)) in groups.enumerate() {
+                total_score +=
+                    badge(group, rucksacks).map_err(|err| err.offset(Pos::new(i * 3 + 1, 1)))?;
+            }
+            Ok(total_score)
+        })?;
+        Ok(total_score.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(Day03.part1(EXAMPLE).unwrap(), "157");
+        assert_eq!(Day03.part2(EXAMPLE).unwrap(), "70");
+    }
+
+    #[test]
+    fn wrong_item() {
+        assert_eq!(find_wrong_item("vJrwpWtwJgWrhcsFMMfFFhFp"), Ok(16));
+    }
+
+    #[test]
+    fn group() {
+        let group = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+        ];
+        assert_eq!(find_group(&group), Ok(18));
+        assert_eq!(
+            find_group(&group[..2]),
+            Err(Error::IncompleteGroup {
+                pos: Pos::default()
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_item() {
+        assert_eq!(
+            find_wrong_item("ab1a"),
+            Err(Error::InvalidItem {
+                pos: Pos::new(1, 3),
+                token: '1',
+            })
+        );
+        assert_eq!(
+            find_group(&["ab", "cd", "eé"]),
+            Err(Error::InvalidItem {
+                pos: Pos::new(3, 2),
+                token: 'é',
+            })
+        );
+        assert_eq!(
+            find_wrong_item("aéa"),
+            Err(Error::InvalidItem {
+                pos: Pos::new(1, 2),
+                token: 'é',
+            })
+        );
+        assert!(matches!(
+            find_wrong_item("abcd"),
+            Err(Error::NoCommonItem { .. })
+        ));
+        assert!(matches!(
+            find_wrong_item("abcda"),
+            Err(Error::OddItems { .. })
+        ));
+    }
+}
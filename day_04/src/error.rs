@@ -0,0 +1,42 @@
+use aoc_common::Pos;
+use std::fmt;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    InvalidSection { pos: Pos, token: String },
+    MissingToken { pos: Pos, expected: &'static str },
+    UnexpectedToken { pos: Pos, token: String },
+}
+
+impl Error {
+    /// Move the error from the position in a single token or line to the
+    /// position in the whole input.
+    pub fn offset(self, origin: Pos) -> Self {
+        match self {
+            Self::InvalidSection { pos, token } => Self::InvalidSection {
+                pos: pos.offset(origin),
+                token,
+            },
+            Self::MissingToken { pos, expected } => Self::MissingToken {
+                pos: pos.offset(origin),
+                expected,
+            },
+            Self::UnexpectedToken { pos, token } => Self::UnexpectedToken {
+                pos: pos.offset(origin),
+                token,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSection { pos, token } => write!(f, "{pos}: invalid section '{token}'"),
+            Self::MissingToken { pos, expected } => write!(f, "{pos}: expected '{expected}'"),
+            Self::UnexpectedToken { pos, token } => write!(f, "{pos}: unexpected '{token}'"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,202 @@
+mod error;
+
+pub use error::Error;
+
+use aoc_common::{columns, phase, Answer, Pos, Solution};
+use log::debug;
+use std::fmt;
+use std::str::FromStr;
+
+/// Split `s` into exactly two parts at `sep`, parsing each with `parse`.
+fn split_pair<T>(
+    s: &str,
+    sep: &'static str,
+    parse: fn(&str) -> Result<T, Error>,
+) -> Result<(T, T), Error> {
+    let mut parts = columns(s, sep);
+    let mut next = || -> Result<T, Error> {
+        let (column, token) = parts.next().ok_or(Error::MissingToken {
+            pos: Pos::new(1, s.len() + 1),
+            expected: sep,
+        })?;
+        parse(token).map_err(|err| err.offset(Pos::new(1, column)))
+    };
+    let pair = (next()?, next()?);
+
+    match parts.next() {
+        Some((column, token)) => Err(Error::UnexpectedToken {
+            pos: Pos::new(1, column - sep.len()),
+            token: sep.to_string() + token,
+        }),
+        None => Ok(pair),
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Sections {
+    pub from: i32,
+    pub to: i32,
+}
+
+impl Sections {
+    pub fn contains(&self, other: Sections) -> bool {
+        self.from <= other.from && self.to >= other.to
+    }
+
+    pub fn overlaps(&self, other: Sections) -> bool {
+        self.from <= other.to && self.to >= other.from
+    }
+}
+
+impl FromStr for Sections {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (from, to) = split_pair(s, "-", |token| {
+            token.parse().map_err(|_| Error::InvalidSection {
+                pos: Pos::default(),
+                token: token.to_string(),
+            })
+        })?;
+        Ok(Self { from, to })
+    }
+}
+
+impl fmt::Display for Sections {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.from, self.to)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Pair {
+    pub first: Sections,
+    pub second: Sections,
+}
+
+impl Pair {
+    pub fn is_fully_overlapping(&self) -> bool {
+        self.first.contains(self.second) || self.second.contains(self.first)
+    }
+
+    pub fn is_overlapping(&self) -> bool {
+        self.first.overlaps(self.second) || self.second.overlaps(self.first)
+    }
+}
+
+impl FromStr for Pair {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (first, second) = split_pair(s, ",", Sections::from_str)?;
+        Ok(Self { first, second })
+    }
+}
+
+impl fmt::Display for Pair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.first, self.second)
+    }
+}
+
+/// Parse every line of the input into a pair of sections.
+fn parse(input: &str) -> Result<Vec<Pair>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse()
+                .map_err(|err: Error| err.offset(Pos::new(i + 1, 1)))
+        })
+        .collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let pairs = phase("parse", || parse(input))?;
+
+        let fully_overlapping = phase("solve", || {
+            let mut fully_overlapping = 0;
+            for pair in pairs {
+                if pair.is_fully_overlapping() {
+                    debug!("Found fully overlapping pair: {pair:?}");
+                    fully_overlapping += 1;
+                }
+            }
+            fully_overlapping
+        });
+        Ok(fully_overlapping.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let pairs = phase("parse", || parse(input))?;
+
+        let overlapping = phase("solve", || {
+            let mut overlapping = 0;
+            for pair in pairs {
+                if pair.is_overlapping() {
+                    debug!("Found overlapping pair: {pair:?}");
+                    overlapping += 1;
+                }
+            }
+            overlapping
+        });
+        Ok(overlapping.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(Day04.part1(EXAMPLE).unwrap(), "2");
+        assert_eq!(Day04.part2(EXAMPLE).unwrap(), "4");
+    }
+
+    #[test]
+    fn overlaps() {
+        let sections = |from, to| Sections { from, to };
+
+        assert!(sections(5, 7).overlaps(sections(7, 9)));
+        assert!(sections(2, 8).overlaps(sections(3, 7)));
+        assert!(!sections(2, 4).overlaps(sections(6, 8)));
+        assert!(!sections(2, 3).overlaps(sections(4, 5)));
+    }
+
+    #[test]
+    fn contains() {
+        let sections = |from, to| Sections { from, to };
+
+        assert!(sections(2, 8).contains(sections(3, 7)));
+        assert!(sections(4, 6).contains(sections(6, 6)));
+        assert!(!sections(5, 7).contains(sections(7, 9)));
+    }
+
+    #[test]
+    fn round_trip() {
+        for line in EXAMPLE.lines() {
+            assert_eq!(line.parse::<Pair>().unwrap().to_string(), line);
+        }
+    }
+
+    #[test]
+    fn invalid_section() {
+        assert_eq!(
+            parse("2-4,6-8\n2-3,4x-5\n"),
+            Err(Error::InvalidSection {
+                pos: Pos::new(2, 5),
+                token: "4x".to_string(),
+            })
+        );
+    }
+}
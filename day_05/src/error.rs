@@ -0,0 +1,74 @@
+use aoc_common::{ParagraphError, Pos};
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    InvalidNumber { pos: Pos, token: String },
+    MissingToken { pos: Pos, expected: &'static str },
+    UnexpectedToken { pos: Pos, token: String },
+    IllegalMove { pos: Pos, token: String },
+}
+
+impl Error {
+    /// Move the error from the position in a single line to the position in
+    /// the whole input.
+    pub fn offset(self, origin: Pos) -> Self {
+        match self {
+            Self::Io(err) => Self::Io(err),
+            Self::InvalidNumber { pos, token } => Self::InvalidNumber {
+                pos: pos.offset(origin),
+                token,
+            },
+            Self::MissingToken { pos, expected } => Self::MissingToken {
+                pos: pos.offset(origin),
+                expected,
+            },
+            Self::UnexpectedToken { pos, token } => Self::UnexpectedToken {
+                pos: pos.offset(origin),
+                token,
+            },
+            Self::IllegalMove { pos, token } => Self::IllegalMove {
+                pos: pos.offset(origin),
+                token,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Problem reading input: {err}"),
+            Self::InvalidNumber { pos, token } => write!(f, "{pos}: invalid number '{token}'"),
+            Self::MissingToken { pos, expected } => write!(f, "{pos}: expected {expected}"),
+            Self::UnexpectedToken { pos, token } => write!(f, "{pos}: unexpected '{token}'"),
+            Self::IllegalMove { pos, token } => write!(f, "{pos}: cannot '{token}'"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ParagraphError<Error>> for Error {
+    fn from(err: ParagraphError<Error>) -> Self {
+        match err {
+            ParagraphError::Io(err) => Self::Io(err),
+            ParagraphError::Parse { pos, err } => err.offset(pos),
+        }
+    }
+}
@@ -0,0 +1,317 @@
+mod error;
+
+pub use error::Error;
+
+use aoc_common::{columns, phase, Answer, Answers, Paragraphs, Pos, Solution};
+use itertools::Itertools;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Move {
+    pub from: usize,
+    pub to: usize,
+    pub count: usize,
+}
+
+impl FromStr for Move {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let line = line.trim_end();
+        let mut parts = columns(line, " ");
+        let mut next = |expected| {
+            parts.next().ok_or(Error::MissingToken {
+                pos: Pos::new(1, line.len() + 1),
+                expected,
+            })
+        };
+
+        let mut numbers = [0; 3];
+        for (keyword, number) in ["move", "from", "to"].into_iter().zip(&mut numbers) {
+            let (column, token) = next(keyword)?;
+            if token != keyword {
+                return Err(Error::UnexpectedToken {
+                    pos: Pos::new(1, column),
+                    token: token.to_string(),
+                });
+            }
+            let (column, token) = next("a number")?;
+            *number = token.parse().map_err(|_| Error::InvalidNumber {
+                pos: Pos::new(1, column),
+                token: token.to_string(),
+            })?;
+        }
+        if let Some((column, token)) = parts.next() {
+            return Err(Error::UnexpectedToken {
+                pos: Pos::new(1, column),
+                token: token.to_string(),
+            });
+        }
+
+        let [count, from, to] = numbers;
+        Ok(Self { from, to, count })
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+/// The crane rearranging the crates.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Crane {
+    /// Moves one crate at a time, as assumed in part 1.
+    CrateMover9000,
+    /// Moves several crates at once, keeping their order, as in part 2.
+    CrateMover9001,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stack {
+    pub idx: i32,
+    pub crates: Vec<char>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stacks(pub Vec<Stack>);
+
+impl Stacks {
+    /// Whether the stacks of the move exist and hold enough crates.
+    pub fn is_legal(&self, mv: &Move) -> bool {
+        let exists = |idx: usize| (1..=self.0.len()).contains(&idx);
+        exists(mv.from) && exists(mv.to) && self.0[mv.from - 1].crates.len() >= mv.count
+    }
+
+    pub fn do_move(&mut self, mv: &Move) {
+        for _ in 0..mv.count {
+            let to_move = self.0[mv.from - 1].crates.pop().unwrap();
+            self.0[mv.to - 1].crates.push(to_move);
+        }
+    }
+
+    pub fn do_move2(&mut self, mv: &Move) {
+        let from = &mut self.0[mv.from - 1].crates;
+        let mut to_move = from.split_off(from.len() - mv.count).to_vec();
+
+        self.0[mv.to - 1].crates.append(&mut to_move);
+    }
+
+    pub fn apply(&mut self, mv: &Move, crane: Crane) {
+        match crane {
+            Crane::CrateMover9000 => self.do_move(mv),
+            Crane::CrateMover9001 => self.do_move2(mv),
+        }
+    }
+
+    pub fn get_top_crates(&self) -> String {
+        self.0
+            .iter()
+            .filter_map(|stack| stack.crates.last())
+            .join("")
+    }
+}
+
+/// Parses the drawing of the starting stacks, down to the line of stack numbers.
+impl FromStr for Stacks {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines: Vec<&str> = s.lines().collect();
+        let mut stacks = vec![];
+        let labels = lines.pop().ok_or(Error::MissingToken {
+            pos: Pos::default(),
+            expected: "stack numbers",
+        })?;
+        for (column, idx) in columns(labels, " ").filter(|(_, idx)| !idx.is_empty()) {
+            stacks.push(Stack {
+                idx: idx.parse().map_err(|_| Error::InvalidNumber {
+                    pos: Pos::new(lines.len() + 1, column),
+                    token: idx.to_string(),
+                })?,
+                crates: vec![],
+            })
+        }
+        lines.reverse();
+
+        for line in lines {
+            for (i, stack) in stacks.iter_mut().enumerate() {
+                match line.chars().nth(i * 4 + 1) {
+                    Some(' ') | None => {}
+                    Some(c) => stack.crates.push(c),
+                }
+            }
+        }
+
+        Ok(Self(stacks))
+    }
+}
+
+/// Draws the stacks the way the puzzle input does.
+impl fmt::Display for Stacks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let height = self.0.iter().map(|stack| stack.crates.len()).max();
+        for row in (0..height.unwrap_or(0)).rev() {
+            let crates = self.0.iter().map(|stack| match stack.crates.get(row) {
+                Some(c) => format!("[{c}]"),
+                None => "   ".to_string(),
+            });
+            writeln!(f, "{}", crates.format(" "))?;
+        }
+        let labels = self.0.iter().map(|stack| format!(" {} ", stack.idx));
+        write!(f, "{}", labels.format(" "))
+    }
+}
+
+/// Parse the starting stacks and the moves, each move with its position.
+fn parse(input: &str) -> Result<(Stacks, Vec<(Pos, Move)>), Error> {
+    let mut paragraphs = Paragraphs::new(input.as_bytes());
+
+    let drawing = paragraphs.next().transpose()?.unwrap_or_default();
+    let stacks = drawing.join("\n").parse()?;
+
+    let moves: Vec<Move> = paragraphs.next_parsed().transpose()?.unwrap_or_default();
+    let first_move = paragraphs.line();
+    let moves = moves
+        .into_iter()
+        .enumerate()
+        .map(|(i, mv)| (Pos::new(first_move + i, 1), mv))
+        .collect();
+
+    for lines in paragraphs.by_ref() {
+        if let Some(line) = lines?.into_iter().next() {
+            return Err(Error::UnexpectedToken {
+                pos: Pos::new(paragraphs.line(), 1),
+                token: line,
+            });
+        }
+    }
+    Ok((stacks, moves))
+}
+
+/// Top crates after the crane did all moves.
+fn arrangement(mut stacks: Stacks, moves: &[(Pos, Move)], crane: Crane) -> Result<String, Error> {
+    for (pos, mv) in moves {
+        if !stacks.is_legal(mv) {
+            return Err(Error::IllegalMove {
+                pos: *pos,
+                token: mv.to_string(),
+            });
+        }
+        stacks.apply(mv, crane);
+    }
+
+    Ok(stacks.get_top_crates())
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let (stacks, moves) = phase("parse", || parse(input))?;
+        Ok(phase("solve", || {
+            arrangement(stacks, &moves, Crane::CrateMover9000)
+        })?)
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let (stacks, moves) = phase("parse", || parse(input))?;
+        Ok(phase("solve", || {
+            arrangement(stacks, &moves, Crane::CrateMover9001)
+        })?)
+    }
+
+    fn answers(&self, input: &str) -> Answer<Answers> {
+        let (stacks, moves) = phase("parse", || parse(input))?;
+        phase("solve", || {
+            Ok(Answers {
+                part1: arrangement(stacks.clone(), &moves, Crane::CrateMover9000)?,
+                part2: arrangement(stacks, &moves, Crane::CrateMover9001)?,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(Day05.part1(EXAMPLE).unwrap(), "CMZ");
+        assert_eq!(Day05.part2(EXAMPLE).unwrap(), "MCD");
+    }
+
+    fn stacks() -> Stacks {
+        let init: Vec<&str> = EXAMPLE
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .collect();
+        init.join("\n").parse().unwrap()
+    }
+
+    #[test]
+    fn do_move() {
+        let mut stacks = stacks();
+        stacks.do_move(&"move 3 from 2 to 1".parse().unwrap());
+
+        assert_eq!(stacks.0[0].crates, vec!['Z', 'N', 'D', 'C', 'M']);
+        assert!(stacks.0[1].crates.is_empty());
+    }
+
+    #[test]
+    fn do_move2() {
+        let mut stacks = stacks();
+        stacks.do_move2(&"move 3 from 2 to 1".parse().unwrap());
+
+        assert_eq!(stacks.0[0].crates, vec!['Z', 'N', 'M', 'C', 'D']);
+        assert!(stacks.0[1].crates.is_empty());
+    }
+
+    #[test]
+    fn round_trip() {
+        let init: Vec<&str> = EXAMPLE
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .collect();
+        assert_eq!(stacks().to_string(), init.join("\n"));
+
+        for line in EXAMPLE.lines().skip(init.len() + 1) {
+            assert_eq!(line.parse::<Move>().unwrap().to_string(), line);
+        }
+    }
+
+    #[test]
+    fn invalid_move() {
+        let input = EXAMPLE.replace("move 2 from 2 to 1", "move 2 from two to 1");
+
+        assert_eq!(
+            Day05.part1(&input).unwrap_err().to_string(),
+            "line 8, column 13: invalid number 'two'"
+        );
+
+        let input = format!("{EXAMPLE}\nmove 1 from 1 to 2\n");
+        assert_eq!(
+            Day05.part1(&input).unwrap_err().to_string(),
+            "line 11, column 1: unexpected 'move 1 from 1 to 2'"
+        );
+    }
+
+    #[test]
+    fn illegal_move() {
+        let input = EXAMPLE.replace("move 1 from 1 to 2", "move 5 from 1 to 2");
+
+        assert_eq!(
+            Day05.part1(&input).unwrap_err().to_string(),
+            "line 9, column 1: cannot 'move 5 from 1 to 2'"
+        );
+    }
+}
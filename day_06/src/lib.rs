@@ -0,0 +1,134 @@
+use aoc_common::{phase, Answer, Solution};
+use log::{debug, trace};
+use std::collections::HashSet;
+
+pub struct WindowIter<T> {
+    pub size: usize,
+    pub buffer: Vec<T>,
+    pub iter: Box<dyn Iterator<Item = T>>,
+}
+
+impl<T: std::clone::Clone> Iterator for WindowIter<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(e) => self.buffer.push(e),
+            None => return None,
+        }
+        if self.buffer.len() > self.size {
+            self.buffer.remove(0);
+        }
+
+        Some(self.buffer.to_vec())
+    }
+}
+
+/// The marker to look for in the datastream.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Marker {
+    /// Start-of-packet marker, as in part 1.
+    Packet,
+    /// Start-of-message marker, as in part 2.
+    Message,
+}
+
+impl Marker {
+    /// Number of distinct characters making up the marker.
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Packet => 4,
+            Self::Message => 14,
+        }
+    }
+}
+
+/// Position after the first window of distinct characters making up the marker.
+pub fn find_marker(contents: &str, marker: Marker) -> Option<usize> {
+    let size = marker.size();
+    let window_iter = WindowIter {
+        size,
+        iter: Box::new(contents.chars().collect::<Vec<char>>().into_iter()),
+        buffer: vec![],
+    };
+
+    for (i, window) in window_iter.enumerate().skip(size - 1) {
+        let uniques: HashSet<char> = HashSet::from_iter(window.clone());
+
+        trace!("{window:?}");
+
+        if uniques.len() == size {
+            debug!("Found {window:?} at marker {}.", i + 1);
+            return Some(i + 1);
+        }
+    }
+    None
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let marker = phase("solve", || find_marker(input, Marker::Packet))
+            .ok_or("No start-of-packet marker found.")?;
+        Ok(marker.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let marker = phase("solve", || find_marker(input, Marker::Message))
+            .ok_or("No start-of-message marker found.")?;
+        Ok(marker.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(Day06.part1(EXAMPLE).unwrap(), "7");
+        assert_eq!(Day06.part2(EXAMPLE).unwrap(), "19");
+    }
+
+    #[test]
+    fn more_examples() {
+        let examples = [
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+        for (contents, packet, message) in examples {
+            assert_eq!(find_marker(contents, Marker::Packet), Some(packet));
+            assert_eq!(find_marker(contents, Marker::Message), Some(message));
+        }
+    }
+
+    #[test]
+    fn window_iter() {
+        let mut window_iter = WindowIter {
+            size: 3,
+            buffer: vec![],
+            iter: Box::new("abcde".chars()),
+        };
+
+        assert_eq!(window_iter.next(), Some(vec!['a']));
+        assert_eq!(window_iter.next(), Some(vec!['a', 'b']));
+        assert_eq!(window_iter.next(), Some(vec!['a', 'b', 'c']));
+        assert_eq!(window_iter.next(), Some(vec!['b', 'c', 'd']));
+        assert_eq!(window_iter.next(), Some(vec!['c', 'd', 'e']));
+        assert_eq!(window_iter.next(), None);
+    }
+
+    #[test]
+    fn no_marker() {
+        assert_eq!(find_marker("abcabc", Marker::Packet), None);
+    }
+}
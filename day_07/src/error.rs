@@ -0,0 +1,29 @@
+use aoc_common::Pos;
+use std::fmt;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    UnknownCommand { pos: Pos, token: String },
+    UnknownDirectory { pos: Pos, token: String },
+    NoParent { pos: Pos },
+    InvalidSize { pos: Pos, token: String },
+    MissingToken { pos: Pos, expected: &'static str },
+    UnexpectedToken { pos: Pos, token: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCommand { pos, token } => write!(f, "{pos}: unknown command '{token}'"),
+            Self::UnknownDirectory { pos, token } => {
+                write!(f, "{pos}: unknown directory '{token}'")
+            }
+            Self::NoParent { pos } => write!(f, "{pos}: cannot go up from the root"),
+            Self::InvalidSize { pos, token } => write!(f, "{pos}: invalid size '{token}'"),
+            Self::MissingToken { pos, expected } => write!(f, "{pos}: expected {expected}"),
+            Self::UnexpectedToken { pos, token } => write!(f, "{pos}: unexpected '{token}'"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
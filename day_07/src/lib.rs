@@ -0,0 +1,101 @@
+mod error;
+pub mod tree;
+
+pub use error::Error;
+
+use aoc_common::{phase, Answer, Answers, Solution};
+use log::{debug, trace};
+use std::cell::RefCell;
+use std::rc::Rc;
+use tree::{init_tree, TreeNode};
+
+/// Sizes of all directories in the tree, with the root first.
+fn dir_sizes(root: &Rc<RefCell<TreeNode>>) -> Vec<u32> {
+    root.borrow_mut().calc_size();
+
+    let mut sizes = vec![];
+    for node in root.borrow().to_owned().into_iter() {
+        let mut borrowed_node = node.borrow_mut();
+        borrowed_node.calc_size();
+        if !borrowed_node.children.is_empty() {
+            sizes.push(borrowed_node.size.unwrap());
+        }
+        trace!("{borrowed_node:?}");
+    }
+    sizes
+}
+
+/// Sum of the sizes of all directories of at most 100000.
+fn small_dirs(sizes: &[u32]) -> u32 {
+    sizes.iter().filter(|&&size| size <= 100000).sum()
+}
+
+/// Size of the smallest directory freeing up enough space for the update.
+fn smallest_to_delete(total: u32, sizes: &[u32]) -> u32 {
+    debug!("Total size: {total}");
+    let unused = 70000000u32.saturating_sub(total);
+    let mut smallest = 70000000;
+    for &size in sizes {
+        if unused + size > 30000000 && size < smallest {
+            smallest = size;
+        }
+    }
+    smallest
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        let binding = phase("parse", || init_tree(input.lines()))?;
+
+        Ok(phase("solve", || small_dirs(&dir_sizes(&binding))).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        let binding = phase("parse", || init_tree(input.lines()))?;
+
+        let smallest = phase("solve", || {
+            let total = binding.borrow_mut().calc_size();
+            smallest_to_delete(total, &dir_sizes(&binding))
+        });
+        Ok(smallest.to_string())
+    }
+
+    fn answers(&self, input: &str) -> Answer<Answers> {
+        let binding = phase("parse", || init_tree(input.lines()))?;
+
+        Ok(phase("solve", || {
+            let total = binding.borrow_mut().calc_size();
+            let sizes = dir_sizes(&binding);
+            Answers {
+                part1: small_dirs(&sizes).to_string(),
+                part2: smallest_to_delete(total, &sizes).to_string(),
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(Day07.part1(EXAMPLE).unwrap(), "95437");
+        assert_eq!(Day07.part2(EXAMPLE).unwrap(), "24933642");
+    }
+
+    #[test]
+    fn dir_sizes_of_example() {
+        let root = init_tree(EXAMPLE.lines()).unwrap();
+
+        assert_eq!(dir_sizes(&root), vec![48381165, 94853, 584, 24933642]);
+    }
+}
@@ -1,3 +1,6 @@
+use crate::error::Error;
+use aoc_common::{columns, Pos};
+use log::trace;
 use std::cell::RefCell;
 use std::fmt;
 use std::rc::Rc;
@@ -72,7 +75,7 @@ impl TreeNode {
     }
 
     pub fn calc_size(&mut self) -> u32 {
-        if let None = self.size {
+        if self.size.is_none() {
             self.size = Some(
                 self.children
                     .iter()
@@ -100,26 +103,50 @@ impl PartialEq for TreeNode {
     }
 }
 
-pub fn init_tree(lines: std::str::Lines) -> Rc<RefCell<TreeNode>> {
-    let mut lines = lines.peekable();
+/// Token `n` of a line split into columns, or an error pointing at the end
+/// of the line if it has less tokens.
+fn token<'a>(
+    split: &[(usize, &'a str)],
+    n: usize,
+    line: (usize, &str),
+    expected: &'static str,
+) -> Result<(usize, &'a str), Error> {
+    split.get(n).copied().ok_or(Error::MissingToken {
+        pos: Pos::new(line.0, line.1.len() + 1),
+        expected,
+    })
+}
+
+pub fn init_tree(lines: std::str::Lines) -> Result<Rc<RefCell<TreeNode>>, Error> {
+    let mut lines = lines.enumerate().map(|(i, line)| (i + 1, line)).peekable();
     let root = TreeNode::new_ref("/", None);
     let mut current = Rc::clone(&root);
 
-    while let Some(line) = lines.next() {
-        let split: Vec<&str> = line.split(" ").collect();
-        match split[1] {
-            "cd" => match split[2] {
-                ".." => {
-                    println!("Going up");
+    while let Some((i, line)) = lines.next() {
+        let split: Vec<(usize, &str)> = columns(line, " ").collect();
+        let (column, prompt) = token(&split, 0, (i, line), "'$'")?;
+        if prompt != "$" {
+            return Err(Error::UnexpectedToken {
+                pos: Pos::new(i, column),
+                token: prompt.to_string(),
+            });
+        }
+        match token(&split, 1, (i, line), "a command")? {
+            (_, "cd") => match token(&split, 2, (i, line), "a directory")? {
+                (column, "..") => {
+                    trace!("Going up");
                     let current_clone = Rc::clone(&current);
-                    current = Rc::clone(current_clone.borrow().parent.as_ref().unwrap());
+                    let parent = current_clone.borrow().parent.clone();
+                    current = parent.ok_or(Error::NoParent {
+                        pos: Pos::new(i, column),
+                    })?;
                 }
-                "/" => {
-                    println!("Going to root");
+                (_, "/") => {
+                    trace!("Going to root");
                     current = Rc::clone(&root);
                 }
-                name => {
-                    println!("Going to {name}");
+                (column, name) => {
+                    trace!("Going to {name}");
                     let current_clone = Rc::clone(&current);
                     current = Rc::clone(
                         current_clone
@@ -127,37 +154,49 @@ pub fn init_tree(lines: std::str::Lines) -> Rc<RefCell<TreeNode>> {
                             .children
                             .iter()
                             .find(|&child| child.borrow().name == name)
-                            .unwrap(),
+                            .ok_or_else(|| Error::UnknownDirectory {
+                                pos: Pos::new(i, column),
+                                token: name.to_string(),
+                            })?,
                     );
                 }
             },
-            "ls" => {
-                while let Some(line) = lines.peek() {
-                    if line.split(" ").collect::<Vec<&str>>()[0] == "$" {
+            (_, "ls") => {
+                while let Some(&(_, line)) = lines.peek() {
+                    if line.split(' ').next() == Some("$") {
                         break;
                     }
 
-                    let line = lines.next().unwrap();
-                    println!("Processing ls for '{line}'");
-                    let split: Vec<&str> = line.split(" ").collect();
+                    let (i, line) = lines.next().unwrap();
+                    trace!("Processing ls for '{line}'");
+                    let split: Vec<(usize, &str)> = columns(line, " ").collect();
+                    let (_, name) = token(&split, 1, (i, line), "a name")?;
 
-                    let child;
-                    match split[0] {
-                        "dir" => child = TreeNode::new_ref(split[1], None),
-                        size => {
-                            child = TreeNode::new_ref(split[1], Some(size.parse().unwrap()));
+                    let child = match split[0] {
+                        (_, "dir") => TreeNode::new_ref(name, None),
+                        (column, size) => {
+                            let size = size.parse().map_err(|_| Error::InvalidSize {
+                                pos: Pos::new(i, column),
+                                token: size.to_string(),
+                            })?;
+                            TreeNode::new_ref(name, Some(size))
                         }
-                    }
+                    };
                     current.borrow_mut().children.push(Rc::clone(&child));
 
                     let mut mut_child = child.borrow_mut();
                     mut_child.parent = Some(Rc::clone(&current));
                 }
             }
-            _ => panic!("Unknown command: '{line}'"),
+            (column, command) => {
+                return Err(Error::UnknownCommand {
+                    pos: Pos::new(i, column),
+                    token: command.to_string(),
+                })
+            }
         }
     }
-    return root;
+    Ok(root)
 }
 
 pub struct TreeNodeIterator {
@@ -179,7 +218,7 @@ impl Iterator for TreeNodeIterator {
             },
         };
 
-        if current == None {
+        if current.is_none() {
             while let Some(parent) = self.stack.pop() {
                 match parent.borrow().next_sibling() {
                     Some(sibling) => {
@@ -213,3 +252,50 @@ impl IntoIterator for TreeNode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add_child(parent: &Rc<RefCell<TreeNode>>, child: Rc<RefCell<TreeNode>>) {
+        child.borrow_mut().parent = Some(Rc::clone(parent));
+        parent.borrow_mut().children.push(child);
+    }
+
+    #[test]
+    fn calc_size() {
+        let root = TreeNode::new_ref("/", None);
+        let dir = TreeNode::new_ref("a", None);
+        add_child(&root, TreeNode::new_ref("b.txt", Some(10)));
+        add_child(&root, Rc::clone(&dir));
+        add_child(&dir, TreeNode::new_ref("c.txt", Some(5)));
+        add_child(&dir, TreeNode::new_ref("d.txt", Some(7)));
+
+        assert_eq!(root.borrow_mut().calc_size(), 22);
+        assert_eq!(dir.borrow().size, Some(12));
+    }
+
+    #[test]
+    fn init_tree_errors() {
+        assert_eq!(
+            init_tree("$ cd /\n$ cd a\n".lines()).unwrap_err(),
+            Error::UnknownDirectory {
+                pos: Pos::new(2, 6),
+                token: "a".to_string(),
+            }
+        );
+        assert_eq!(
+            init_tree("$ cd ..\n".lines()).unwrap_err(),
+            Error::NoParent {
+                pos: Pos::new(1, 6)
+            }
+        );
+        assert_eq!(
+            init_tree("$ rm a\n".lines()).unwrap_err(),
+            Error::UnknownCommand {
+                pos: Pos::new(1, 3),
+                token: "rm".to_string(),
+            }
+        );
+    }
+}